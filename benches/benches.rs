@@ -1,10 +1,12 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use svg::node::element::{Circle, Rectangle};
 use svg::Document;
 
 use geopattern::{
-    chevrons, concentric_circles, diamonds, hexagons, joy_division, mosaic_squares, nested_squares,
-    octagons, overlapping_circles, overlapping_rings, plaid, plus_signs, sine_waves, squares,
-    tesselation, tiled_lines, triangles, triangular_mesh, un_deus_trois, xes,
+    chevrons, circle_packing, concentric_circles, diamonds, hexagons, joy_division, mosaic_squares,
+    nested_squares, octagons, overlapping_circles, overlapping_rings, plaid, plus_signs,
+    sine_waves, squares, tesselation, tiled_lines, triangles, triangular_mesh, un_deus_trois, xes,
 };
 
 fn chevrons_bench(c: &mut Criterion) {
@@ -28,6 +30,139 @@ fn chevrons_bench(c: &mut Criterion) {
     });
 }
 
+fn circle_packing_centers(n: usize, (width, height): (f32, f32)) -> Vec<(f32, f32)> {
+    let mut rng = SmallRng::seed_from_u64(7);
+
+    (0..n)
+        .map(|_| (rng.gen_range(0.0, width), rng.gen_range(0.0, height)))
+        .collect()
+}
+
+/// the previous implementation, copied from before the grid index, checking every placed circle and
+/// growing radii a unit at a time
+#[allow(clippy::needless_borrow)]
+fn circle_packing_naive(
+    centers: &[(f32, f32)],
+    (minr, maxr): (f32, f32),
+    (width, height): (f32, f32),
+    fill: &[(&str, f32)],
+    stroke: (&str, f32, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(centers.len(), fill.len());
+
+    // create_document is private, so the background is added the same way here
+    let mut doc = Document::new()
+        .set("width", width)
+        .set("height", height)
+        .add(
+            Rectangle::new()
+                .set("x", 0)
+                .set("y", 0)
+                .set("width", "100%")
+                .set("height", "100%")
+                .set("fill", background_color),
+        );
+
+    let mut circles: Vec<(f32, f32, f32)> = Vec::new();
+
+    // explicit immutable borrow to avoid collision with mutable borrow in loop
+    // https://stackoverflow.com/a/57690260
+    let has_collision = |(x, y, r): (f32, f32, f32), circles: &Vec<(f32, f32, f32)>| {
+        if x + r > width || x - r < 0.0 {
+            return true;
+        }
+
+        if y + r > height || y - r < 0.0 {
+            return true;
+        }
+
+        for c in circles.iter() {
+            let a = r + c.2;
+            let dx = x - c.0;
+            let dy = y - c.1;
+
+            if a * a >= dx * dx + dy * dy {
+                return true;
+            }
+        }
+
+        false
+    };
+
+    let fit_radius = |(x, y): (f32, f32), circles: &Vec<(f32, f32, f32)>| {
+        let mut r = minr;
+
+        while r < maxr {
+            if has_collision((x, y, r), &circles) {
+                r -= 1.0;
+                break;
+            }
+            r += 1.0;
+        }
+
+        r
+    };
+
+    for (i, (x, y)) in centers.iter().enumerate() {
+        let (x, y) = (*x, *y);
+
+        if has_collision((x, y, minr), &circles) {
+            continue;
+        }
+
+        let r = fit_radius((x, y), &circles);
+
+        circles.push((x, y, r));
+        doc = doc.add(
+            Circle::new()
+                .set("cx", x)
+                .set("cy", y)
+                .set("r", r)
+                .set("fill", fill[i].0)
+                .set("fill-opacity", fill[i].1)
+                .set("stroke", stroke.0)
+                .set("stroke-width", stroke.1)
+                .set("stroke-opacity", stroke.2),
+        );
+    }
+
+    doc
+}
+
+fn circle_packing_bench(c: &mut Criterion) {
+    let centers = circle_packing_centers(5000, (1000.0, 1000.0));
+    let fill = (0..5000)
+        .map(|i| (if i & 1 == 0 { "#ddd" } else { "#222" }, 0.5))
+        .collect::<Vec<(&str, f32)>>();
+
+    c.bench_function("circle_packing", |b| {
+        b.iter(|| {
+            let _document: Document = circle_packing(
+                black_box(&centers),
+                black_box((1.0, 50.0)),
+                black_box((1000.0, 1000.0)),
+                &fill,
+                black_box(("#ddd", 1.0, 0.5)),
+                black_box("#998877"),
+            );
+        })
+    });
+
+    c.bench_function("circle_packing_naive", |b| {
+        b.iter(|| {
+            let _document: Document = circle_packing_naive(
+                black_box(&centers),
+                black_box((1.0, 50.0)),
+                black_box((1000.0, 1000.0)),
+                &fill,
+                black_box(("#ddd", 1.0, 0.5)),
+                black_box("#998877"),
+            );
+        })
+    });
+}
+
 fn concentric_circles_bench(c: &mut Criterion) {
     c.bench_function("concentric_circles", |b| {
         b.iter(|| {
@@ -345,7 +480,7 @@ fn tiled_lines_bench(c: &mut Criterion) {
     c.bench_function("tiled_lines", |b| {
         b.iter(|| {
             let _document: Document = tiled_lines(
-                black_box(60),
+                black_box(60.0),
                 (5, 4),
                 &(0..20).map(|i| i & 1 == 0).collect::<Vec<bool>>(),
                 &(0..20)
@@ -449,6 +584,7 @@ fn xes_bench(c: &mut Criterion) {
 criterion_group!(
     benches,
     chevrons_bench,
+    circle_packing_bench,
     concentric_circles_bench,
     diamonds_bench,
     hexagons_bench,
@@ -1,45 +1,108 @@
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 use sha1::{Digest, Sha1};
+use svg::node::element::Path;
 use svg::save;
 
+use geopattern::noise::{Noise, NoiseKind};
 use geopattern::{
-    chevrons, circle_packing, concentric_circles, cubic_disarray, diamonds, hexagons,
-    hypnotic_squares, joy_division, mosaic_squares, nested_squares, octagons, overlapping_circles,
-    overlapping_rings, piet_mondrian, plaid, plus_signs, sine_waves, squares, tesselation,
-    tiled_lines, triangles, triangular_mesh, un_deus_trois, xes, PietMondrianSplitType,
+    argyle, chevrons, circle_packing, circle_packing_in, concentric_circles, contours,
+    cubic_disarray, cubic_disarray_with, diamonds, flow_field, gingham, halftone, herringbone,
+    hexagons, hexagons_with, hitomezashi, houndstooth, hypnotic_squares, hypnotic_squares_with,
+    isometric_cubes, joy_division, joy_division_classic, lsystem, lsystem::LSystem, maze,
+    mosaic_squares, nested_squares, octagons, overlapping_circles, overlapping_rings,
+    piet_mondrian, piet_mondrian_layout, piet_mondrian_random, piet_mondrian_with, plaid,
+    plus_signs, scales, seigaiha, sine_waves, sine_waves_with, space_filling_curve, squares,
+    tartan, tartan_sett, tesselation, tiled_lines, tiled_lines_with, triangles, triangular_mesh,
+    un_deus_trois, un_deus_trois_with, uniform_tiling, wallpaper, xes, CirclePackingBounds,
+    CubicDisarrayOffsets, CubicDisarrayShape, FlowFieldNoise, HalftoneLattice, HalftoneShape,
+    HalftoneSource, HexagonFill, HexagonOrientation, HypnoticSquaresDirection,
+    HypnoticSquaresShape, IsometricCubesVariant, MazeAlgorithm, MazeStyle, PietMondrianSplitType,
+    SineWavesOrientation, SineWavesShape, SpaceFillingCurve, TiledLinesDirection,
+    UnDeusTroisRotation, UniformTiling, WallpaperGroup,
 };
 
 fn main() -> anyhow::Result<()> {
     let digest = Sha1::digest(b"geopattern");
 
+    write_argyle(&digest)?;
     write_chevrons(&digest)?;
     write_circle_packing(&digest)?;
+    write_circle_packing_in(&digest)?;
     write_concentric_circles(&digest)?;
+    write_contours(&digest)?;
     write_cubic_disarray(&digest)?;
+    write_cubic_disarray_with(&digest)?;
     write_diamonds(&digest)?;
+    write_flow_field(&digest)?;
+    write_gingham(&digest)?;
+    write_halftone(&digest)?;
+    write_herringbone(&digest)?;
     write_hexagons(&digest)?;
+    write_hexagons_with(&digest)?;
+    write_hitomezashi(&digest)?;
+    write_houndstooth(&digest)?;
     write_hypnotic_squares(&digest)?;
+    write_hypnotic_squares_with(&digest)?;
+    write_isometric_cubes(&digest)?;
     write_joy_division(&digest)?;
+    write_joy_division_classic(&digest)?;
+    write_lsystem(&digest)?;
+    write_maze(&digest)?;
     write_mosaic_squares(&digest)?;
     write_nested_squares(&digest)?;
     write_octagons(&digest)?;
     write_overlapping_circles(&digest)?;
     write_overlapping_rings(&digest)?;
     write_piet_mondrian(&digest)?;
+    write_piet_mondrian_random(&digest)?;
+    write_piet_mondrian_with(&digest)?;
     write_plaid(&digest)?;
     write_plus_signs(&digest)?;
+    write_scales(&digest)?;
+    write_seigaiha(&digest)?;
     write_sine_waves(&digest)?;
+    write_sine_waves_with(&digest)?;
+    write_space_filling_curve(&digest)?;
     write_squares(&digest)?;
+    write_tartan(&digest)?;
     write_tesselation(&digest)?;
     write_tiled_lines(&digest)?;
+    write_tiled_lines_with(&digest)?;
     write_triangles(&digest)?;
     write_triangular_mesh(&digest)?;
     write_un_deus_trois(&digest)?;
+    write_un_deus_trois_with(&digest)?;
+    write_uniform_tiling(&digest)?;
+    write_wallpaper(&digest)?;
     write_xes(&digest)?;
 
     Ok(())
 }
 
+fn write_argyle(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/argyle.svg",
+        &argyle(
+            (64.0, 96.0),
+            (4, 3),
+            (
+                (
+                    &format!("rgb({},{},{})", digest[0], digest[1], digest[2]),
+                    0.8,
+                ),
+                (
+                    &format!("rgb({},{},{})", digest[3], digest[4], digest[5]),
+                    0.8,
+                ),
+            ),
+            ("#ddd", 0.8, 1.5),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_chevrons(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/chevrons.svg",
@@ -99,6 +162,45 @@ fn write_circle_packing(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_circle_packing_in(digest: &[u8]) -> anyhow::Result<()> {
+    let mut rng = SmallRng::seed_from_u64(digest[10] as u64 * 256 + digest[11] as u64);
+    let (w, h) = (256.0, 256.0);
+
+    let points = (0..3000)
+        .map(|_| (rng.gen_range(0.0, w), rng.gen_range(0.0, h)))
+        .collect::<Vec<(f32, f32)>>();
+
+    let colors = (0..3000)
+        .map(|i| {
+            format!(
+                "rgb({},{},{})",
+                digest[i % 20],
+                digest[(2 * i + 1) % 20],
+                digest[(3 * i + 2) % 20]
+            )
+        })
+        .collect::<Vec<String>>();
+
+    save(
+        "examples/readme/circle_packing_in.svg",
+        &circle_packing_in(
+            CirclePackingBounds::Toroidal,
+            &points,
+            (0.5, 48.0),
+            (w, h),
+            (2, 1.5),
+            &colors
+                .iter()
+                .map(|c| (c.as_str(), 0.8))
+                .collect::<Vec<(&str, f32)>>(),
+            ("#222", 1.0, 0.5),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_concentric_circles(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/concentric_circles.svg",
@@ -130,6 +232,40 @@ fn write_concentric_circles(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_contours(digest: &[u8]) -> anyhow::Result<()> {
+    let fill = (0..9)
+        .map(|i| {
+            format!(
+                "rgb({},{},{})",
+                digest[0] as usize * (8 - i) / 8 + digest[1] as usize * i / 8,
+                digest[2] as usize * (8 - i) / 8 + digest[3] as usize * i / 8,
+                digest[4] as usize * (8 - i) / 8 + digest[5] as usize * i / 8,
+            )
+        })
+        .collect::<Vec<String>>();
+
+    save(
+        "examples/readme/contours.svg",
+        &contours(
+            (256.0, 256.0),
+            &Noise {
+                octaves: 3,
+                ..Noise::new(NoiseKind::Simplex, digest[6] as u64, 1.0 / 96.0)
+            },
+            4.0,
+            &[("#222", 0.6); 8],
+            1.0,
+            &fill
+                .iter()
+                .map(|c| (c.as_str(), 1.0))
+                .collect::<Vec<(&str, f32)>>(),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_cubic_disarray(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/cubic_disarray.svg",
@@ -166,6 +302,29 @@ fn write_cubic_disarray(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_cubic_disarray_with(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/cubic_disarray_with.svg",
+        &cubic_disarray_with(
+            32.0,
+            (12, 12),
+            CubicDisarrayShape::Hexagon,
+            &CubicDisarrayOffsets::Progressive(
+                digest[4] as u64 * 256 + digest[5] as u64,
+                (10.0, 6.0),
+                30.0,
+            ),
+            &(0..144)
+                .map(|_| ("#FFF", 0.8))
+                .collect::<Vec<(&str, f32)>>(),
+            ("#333", 1.0),
+            &format!("rgb({},{},{})", 200, 200, 200),
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_diamonds(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/diamonds.svg",
@@ -188,6 +347,88 @@ fn write_diamonds(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_flow_field(digest: &[u8]) -> anyhow::Result<()> {
+    let colors = (0..4)
+        .map(|i| format!("rgb({},{},{})", digest[i], digest[i + 4], digest[i + 8]))
+        .collect::<Vec<String>>();
+
+    save(
+        "examples/readme/flow_field.svg",
+        &flow_field(
+            (256.0, 256.0),
+            FlowFieldNoise::Curl,
+            (digest[12] as u64, 0.008),
+            8.0,
+            &colors
+                .iter()
+                .map(|c| (c.as_str(), 0.9))
+                .collect::<Vec<(&str, f32)>>(),
+            (4.0, 0.9),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_gingham(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/gingham.svg",
+        &gingham(
+            16.0,
+            (8, 8),
+            (
+                &format!("rgb({},{},{})", digest[6], digest[7], digest[8]),
+                0.5,
+            ),
+            "#eee",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_halftone(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/halftone.svg",
+        &halftone(
+            10.0,
+            (256.0, 256.0),
+            (HalftoneLattice::Hexagonal, 15.0),
+            &HalftoneSource::Noise(Noise {
+                octaves: 2,
+                ..Noise::new(NoiseKind::Perlin, digest[7] as u64, 1.0 / 80.0)
+            }),
+            HalftoneShape::Circle,
+            (
+                &format!("rgb({},{},{})", digest[8], digest[9], digest[10]),
+                1.0,
+            ),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_herringbone(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/herringbone.svg",
+        &herringbone(
+            4.0,
+            8,
+            (4, 16),
+            (
+                &format!("rgb({},{},{})", digest[9], digest[10], digest[11]),
+                1.0,
+            ),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_hexagons(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/hexagons.svg",
@@ -210,6 +451,71 @@ fn write_hexagons(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_hexagons_with(digest: &[u8]) -> anyhow::Result<()> {
+    let rings = (0..6)
+        .map(|i| format!("rgb({},{},{})", 255 - i * 30, 80 + i * 20, 60 + i * 30))
+        .collect::<Vec<String>>();
+
+    save(
+        "examples/readme/hexagons_with.svg",
+        &hexagons_with(
+            16.0,
+            (10, 12),
+            (HexagonOrientation::Pointy, 3.0, 4.0),
+            &HexagonFill::Rings(
+                (digest[0] as usize % 10, digest[1] as usize % 12),
+                &rings
+                    .iter()
+                    .map(|c| (c.as_str(), 0.9))
+                    .collect::<Vec<(&str, f32)>>(),
+            ),
+            ("#000", 0.0),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_hitomezashi(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/hitomezashi.svg",
+        &hitomezashi(
+            16.0,
+            &(0..20).map(|i| digest[i] & 1 == 0).collect::<Vec<bool>>(),
+            &(0..20).map(|i| digest[i] & 2 == 0).collect::<Vec<bool>>(),
+            ("#222", 0.8, 2.0),
+            &[
+                ("#ddd", 0.8),
+                (
+                    &format!("rgb({},{},{})", digest[3], digest[7], digest[11]),
+                    0.8,
+                ),
+            ],
+            "#ddd",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_houndstooth(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/houndstooth.svg",
+        &houndstooth(
+            4.0,
+            (8, 8),
+            (
+                &format!("rgb({},{},{})", digest[12], digest[13], digest[14]),
+                1.0,
+            ),
+            "#eee",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_hypnotic_squares(digest: &[u8]) -> anyhow::Result<()> {
     let colors: Vec<String> = (2..18)
         .map(|i| {
@@ -247,6 +553,81 @@ fn write_hypnotic_squares(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_hypnotic_squares_with(digest: &[u8]) -> anyhow::Result<()> {
+    let directions = [
+        HypnoticSquaresDirection::UpLeft,
+        HypnoticSquaresDirection::Up,
+        HypnoticSquaresDirection::UpRight,
+        HypnoticSquaresDirection::Left,
+        HypnoticSquaresDirection::Center,
+        HypnoticSquaresDirection::Right,
+        HypnoticSquaresDirection::DownLeft,
+        HypnoticSquaresDirection::Down,
+        HypnoticSquaresDirection::DownRight,
+    ];
+
+    let colors: Vec<String> = (2..18)
+        .map(|i| {
+            format!(
+                "rgb({},{},{})",
+                digest[i % 20] / 2,
+                digest[2 * i % 20] / 2,
+                digest[3 * i % 20] / 2
+            )
+        })
+        .collect();
+
+    save(
+        "examples/readme/hypnotic_squares_with.svg",
+        &hypnotic_squares_with(
+            72.0,
+            40.0,
+            8,
+            (4, 4),
+            &(0..16)
+                .map(|i| directions[digest[i] as usize % 9])
+                .collect::<Vec<HypnoticSquaresDirection>>(),
+            HypnoticSquaresShape::Circle,
+            &(0..16)
+                .map(|i| ((colors[i].as_str(), "#eee"), 2.0, 1.0))
+                .collect::<Vec<((&str, &str), f32, f32)>>(),
+            "#111",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_isometric_cubes(digest: &[u8]) -> anyhow::Result<()> {
+    let colors: Vec<String> = (0..24)
+        .map(|i| {
+            format!(
+                "rgb({},{},{})",
+                128 + digest[i % 20] / 2,
+                128 + digest[(i + 7) % 20] / 2,
+                128 + digest[(i + 13) % 20] / 2
+            )
+        })
+        .collect();
+
+    save(
+        "examples/readme/isometric_cubes.svg",
+        &isometric_cubes(
+            24.0,
+            (4, 6),
+            IsometricCubesVariant::Cubes,
+            &(0..24)
+                .map(|i| (colors[i].as_str(), 1.0))
+                .collect::<Vec<(&str, f32)>>(),
+            &(0..24).map(|i| digest[i % 20] > 40).collect::<Vec<bool>>(),
+            ("#222", 0.4),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_joy_division(digest: &[u8]) -> anyhow::Result<()> {
     let (width, height, step_size) = (26, 13, 16.0);
     let mut pulse_heights = vec![0.0; width * height];
@@ -260,7 +641,7 @@ fn write_joy_division(digest: &[u8]) -> anyhow::Result<()> {
             };
             let variance = if variance < width / 4 { 0 } else { variance };
             pulse_heights[ix] =
-                (digest[ix % 20] as f32 / 255.0) * (variance as f32 * step_size / 4.0) * -1.0;
+                -(digest[ix % 20] as f32 / 255.0) * (variance as f32 * step_size / 4.0);
         }
     }
 
@@ -279,6 +660,60 @@ fn write_joy_division(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_joy_division_classic(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/joy_division_classic.svg",
+        &joy_division_classic(
+            6.0,
+            (48, 40),
+            digest[0] as u64 * 256 + digest[1] as u64,
+            (0.5, 60.0),
+            ("#FFF", 1.0, 1.5),
+            10,
+            "#111",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_lsystem(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/lsystem.svg",
+        &lsystem(
+            &LSystem::dragon_curve(),
+            10,
+            (256.0, 256.0),
+            (
+                &format!("rgb({},{},{})", digest[0], digest[1], digest[2]),
+                1.0,
+                2.0,
+            ),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_maze(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/maze.svg",
+        &maze(
+            24.0,
+            (12, 12),
+            digest[0] as u64 * 256 + digest[1] as u64,
+            MazeAlgorithm::Wilson,
+            true,
+            MazeStyle::Passages,
+            ("#ddd", 0.8, 8.0, "round"),
+            &format!("rgb({},{},{})", digest[9], digest[10], digest[11]),
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_mosaic_squares(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/mosaic_squares.svg",
@@ -422,6 +857,66 @@ fn write_piet_mondrian(_digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_piet_mondrian_random(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/piet_mondrian_random.svg",
+        &piet_mondrian_random(
+            digest[0] as u64 * 256 + digest[1] as u64,
+            (300.0, 300.0),
+            300.0 / 7.0,
+            (0.08, 0.08, 0.08),
+            ("#222", 8.0, 1.0),
+            "#FFF",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_piet_mondrian_with(digest: &[u8]) -> anyhow::Result<()> {
+    let splits = (1..10)
+        .flat_map(|i| {
+            vec![
+                (PietMondrianSplitType::X, i as f32 * 30.0),
+                (PietMondrianSplitType::Y, i as f32 * 30.0),
+            ]
+        })
+        .collect::<Vec<(PietMondrianSplitType, f32)>>();
+
+    let rectangles = piet_mondrian_layout(
+        &splits,
+        (300.0, 300.0),
+        (digest[2] as u64 * 256 + digest[3] as u64, 0.4),
+    );
+
+    save(
+        "examples/readme/piet_mondrian_with.svg",
+        &piet_mondrian_with(
+            &rectangles,
+            (300.0, 300.0),
+            |i, (_, _, w, h)| {
+                if w * h < 2000.0 {
+                    ("#F2F2F2".to_string(), 1.0)
+                } else {
+                    (
+                        format!(
+                            "rgb({},{},{})",
+                            digest[i % 20],
+                            digest[(i + 7) % 20],
+                            digest[(i + 13) % 20]
+                        ),
+                        1.0,
+                    )
+                }
+            },
+            ("#222", 4.0, 1.0),
+            "#FFF",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_plaid(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/plaid.svg",
@@ -469,6 +964,60 @@ fn write_plus_signs(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_scales(digest: &[u8]) -> anyhow::Result<()> {
+    let colors = (0..72)
+        .map(|i| {
+            let shade = digest[i % 20] as usize / 4;
+            format!("rgb({},{},{})", 40 + shade, 100 + shade, 90 + shade / 2)
+        })
+        .collect::<Vec<String>>();
+
+    save(
+        "examples/readme/scales.svg",
+        &scales(
+            24.0,
+            (6, 12),
+            2,
+            &(0..144)
+                .map(|i| {
+                    if i & 1 == 0 {
+                        (colors[i / 2].as_str(), 1.0)
+                    } else {
+                        ("#222", 0.2)
+                    }
+                })
+                .collect::<Vec<(&str, f32)>>(),
+            ("#222", 0.6),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_seigaiha(digest: &[u8]) -> anyhow::Result<()> {
+    let colors = [
+        format!("rgb({},{},{})", digest[0], digest[1], digest[2]),
+        "#eee".to_string(),
+    ];
+
+    save(
+        "examples/readme/seigaiha.svg",
+        &seigaiha(
+            32.0,
+            (4, 16),
+            6,
+            &(0..384)
+                .map(|i| (colors[i % 2].as_str(), 1.0))
+                .collect::<Vec<(&str, f32)>>(),
+            ("#222", 0.0),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_sine_waves(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/sine_waves.svg",
@@ -491,6 +1040,72 @@ fn write_sine_waves(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_sine_waves_with(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/sine_waves_with.svg",
+        &sine_waves_with(
+            200.0,
+            6.0,
+            (SineWavesOrientation::Diagonal, SineWavesShape::Sine),
+            &(0..24)
+                .map(|i| {
+                    (
+                        4.0 + 12.0 * (std::f32::consts::PI * i as f32 / 24.0).sin(),
+                        1 + digest[i % 20] as usize % 3,
+                        i as f32 / 24.0,
+                    )
+                })
+                .collect::<Vec<(f32, usize, f32)>>(),
+            &(0..24)
+                .map(|i| {
+                    (
+                        if digest[i % 20] & 1 == 0 {
+                            "#ddd"
+                        } else {
+                            "#222"
+                        },
+                        0.1 + (digest[i % 20] as f32 * 0.4) / 255.0,
+                    )
+                })
+                .collect::<Vec<(&str, f32)>>(),
+            &format!("rgb({},{},{})", digest[10], digest[13], digest[14]),
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_space_filling_curve(digest: &[u8]) -> anyhow::Result<()> {
+    let colors = (0..16)
+        .map(|i| {
+            format!(
+                "rgb({},{},{})",
+                digest[0] as usize * (15 - i) / 15 + digest[1] as usize * i / 15,
+                digest[2] as usize * (15 - i) / 15 + digest[3] as usize * i / 15,
+                digest[4] as usize * (15 - i) / 15 + digest[5] as usize * i / 15,
+            )
+        })
+        .collect::<Vec<String>>();
+
+    save(
+        "examples/readme/space_filling_curve.svg",
+        &space_filling_curve(
+            SpaceFillingCurve::Moore,
+            4,
+            (256.0, 256.0),
+            &colors
+                .iter()
+                .map(|c| (c.as_str(), 1.0))
+                .collect::<Vec<(&str, f32)>>(),
+            6.0,
+            4.0,
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_squares(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/squares.svg",
@@ -513,6 +1128,29 @@ fn write_squares(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_tartan(digest: &[u8]) -> anyhow::Result<()> {
+    let (k, g, b) = (
+        format!("rgb({},{},{})", digest[0] / 4, digest[1] / 4, digest[2] / 4),
+        format!("rgb({},{},{})", digest[3], digest[4], digest[5]),
+        format!("rgb({},{},{})", digest[6], digest[7], digest[8]),
+    );
+
+    save(
+        "examples/readme/tartan.svg",
+        &tartan(
+            2.0,
+            &tartan_sett(
+                "B/24 K4 B4 K4 B4 K20 G24 K6 G24 K20 B22 K4 B/4",
+                &[("K", &k), ("G", &g), ("B", &b)],
+            )
+            .ok_or_else(|| anyhow::anyhow!("invalid sett"))?,
+            true,
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_tesselation(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/tesselation.svg",
@@ -549,7 +1187,7 @@ fn write_tiled_lines(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/tiled_lines.svg",
         &tiled_lines(
-            48,
+            48.0,
             (8, 8),
             &(0..64)
                 .map(|v| digest[v % 20] & 1 == 0)
@@ -565,6 +1203,33 @@ fn write_tiled_lines(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_tiled_lines_with(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/tiled_lines_with.svg",
+        &tiled_lines_with(
+            32.0,
+            (12, 12),
+            &(0..144)
+                .map(|v| {
+                    if (digest[v % 20] >> (v / 20 % 8)) & 1 == 0 {
+                        TiledLinesDirection::Horizontal
+                    } else {
+                        TiledLinesDirection::Vertical
+                    }
+                })
+                .collect::<Vec<TiledLinesDirection>>(),
+            3,
+            &(0..144)
+                .map(|_| ("#ddd", 0.9))
+                .collect::<Vec<(&str, f32)>>(),
+            3.0,
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_triangles(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/triangles.svg",
@@ -648,6 +1313,95 @@ fn write_un_deus_trois(digest: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn write_un_deus_trois_with(digest: &[u8]) -> anyhow::Result<()> {
+    save(
+        "examples/readme/un_deus_trois_with.svg",
+        &un_deus_trois_with(
+            32.0,
+            (12, 3),
+            &[1, 2, 3, 4],
+            &UnDeusTroisRotation::Choice(
+                digest[6] as u64 * 256 + digest[7] as u64,
+                &[0.0, 45.0, 90.0, 135.0],
+            ),
+            &(0..144)
+                .map(|i| {
+                    (
+                        if i < 36 { "#ddd" } else { "#aaa" },
+                        8.0 - (i / 36) as f32 * 2.0,
+                        0.9,
+                    )
+                })
+                .collect::<Vec<(&str, f32, f32)>>(),
+            true,
+            "#141414",
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_uniform_tiling(digest: &[u8]) -> anyhow::Result<()> {
+    let tiling = UniformTiling::Rhombitrihexagonal;
+
+    save(
+        "examples/readme/uniform_tiling.svg",
+        &uniform_tiling(
+            tiling,
+            24.0,
+            (2, 3),
+            &(0..tiling.face_types())
+                .map(|i| {
+                    (
+                        if digest[i] & 1 == 0 { "#ddd" } else { "#222" },
+                        0.1 + (digest[i] as f32 * 0.4) / 255.0,
+                    )
+                })
+                .collect::<Vec<(&str, f32)>>(),
+            ("#ddd", 0.2),
+            &format!("rgb({},{},{})", digest[3], digest[9], digest[12]),
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn write_wallpaper(digest: &[u8]) -> anyhow::Result<()> {
+    let side = 72.0;
+
+    save(
+        "examples/readme/wallpaper.svg",
+        &wallpaper(
+            WallpaperGroup::P31m,
+            Path::new()
+                .set(
+                    "d",
+                    format!(
+                        "M {} {} L {} {} L {} {} Z",
+                        side * 0.1,
+                        side * 0.05,
+                        side * (0.2 + digest[0] as f32 / 1024.0),
+                        side * 0.1,
+                        side * 0.15,
+                        side * (0.2 + digest[1] as f32 / 1024.0),
+                    ),
+                )
+                .set(
+                    "fill",
+                    format!("rgb({},{},{})", digest[2], digest[3], digest[4]),
+                )
+                .set("fill-opacity", 0.8)
+                .set("stroke", "#ddd")
+                .set("stroke-opacity", 0.4),
+            (side, side * 3.0_f32.sqrt()),
+            (3, 2),
+            "#222",
+        ),
+    )?;
+
+    Ok(())
+}
+
 fn write_xes(digest: &[u8]) -> anyhow::Result<()> {
     save(
         "examples/readme/xes.svg",
@@ -2,10 +2,19 @@
 
 #![deny(missing_docs)]
 
-use svg::node::element::{Circle, Group, Path, Polyline, Rectangle};
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+
+use svg::node::element::{Circle, Ellipse, Group, Path, Polyline, Rectangle};
 use svg::node::Value;
 use svg::Document;
 
+pub mod lsystem;
+pub mod noise;
+
+use lsystem::LSystem;
+use noise::{Noise, NoiseKind};
+
 fn create_document<V>((width, height): (V, V), background_color: &str) -> Document
 where
     V: Into<Value>,
@@ -23,6 +32,144 @@ where
         )
 }
 
+/// splitmix64, a small deterministic generator for the patterns that are generated from a seed
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// uniform in `[0, 1)`
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// uniform in `[0, n)`
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Argyle
+///
+/// https://en.wikipedia.org/wiki/Argyle_(pattern)
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/argyle.svg)
+///
+/// A checkerboard of diamonds in the two colours of `fill`, with diagonal overstitch lines that
+/// cross at the diamond centers and pass through the midpoints of their edges.
+///
+/// ```
+/// use geopattern::argyle;
+///
+/// let c = argyle(
+///     (40.0, 60.0),
+///     (2, 2),
+///     (("#222", 0.6), ("#ddd", 0.4)),
+///     ("#ddd", 0.8, 1.0),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn argyle(
+    (diamond_width, diamond_height): (f32, f32),
+    (width, height): (usize, usize),
+    fill: ((&str, f32), (&str, f32)),
+    (stroke_color, stroke_opacity, stroke_width): (&str, f32, f32),
+    background_color: &str,
+) -> Document {
+    let (w, h) = (diamond_width, diamond_height);
+    let (tile_width, tile_height) = (w * width as f32, h * height as f32);
+
+    let mut doc = create_document((tile_width, tile_height), background_color);
+
+    let diamond = |(cx, cy): (f32, f32), (color, opacity): (&str, f32)| {
+        Polyline::new()
+            .set(
+                "points",
+                polygon_points(&[
+                    (cx - w / 2.0, cy),
+                    (cx, cy - h / 2.0),
+                    (cx + w / 2.0, cy),
+                    (cx, cy + h / 2.0),
+                ]),
+            )
+            .set("fill", color)
+            .set("fill-opacity", opacity)
+    };
+
+    // the diamonds on the corners of the cells are cut by the edges of the tile
+    for y in 0..=height {
+        for x in 0..=width {
+            doc = doc.add(diamond((x as f32 * w, y as f32 * h), fill.0));
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            doc = doc.add(diamond(
+                (x as f32 * w + w / 2.0, y as f32 * h + h / 2.0),
+                fill.1,
+            ));
+        }
+    }
+
+    // lines through every diamond center, extended a cell past the edges so they leave the tile
+    // at full width
+    let mut path = String::new();
+    let run = (height + 2) as f32 * w;
+
+    for i in -(height as isize) - 1..=width as isize + 1 {
+        let x = i as f32 * w;
+
+        path.push_str(&format!(
+            " M {} {} L {} {}",
+            x - w,
+            -h,
+            x - w + run,
+            tile_height + h,
+        ));
+    }
+
+    for i in -1..=(width + height) as isize + 1 {
+        let x = i as f32 * w;
+
+        path.push_str(&format!(
+            " M {} {} L {} {}",
+            x + w,
+            -h,
+            x + w - run,
+            tile_height + h,
+        ));
+    }
+
+    doc.add(
+        Path::new()
+            .set("d", path.trim_start())
+            .set("fill", "none")
+            .set("stroke", stroke_color)
+            .set("stroke-opacity", stroke_opacity)
+            .set("stroke-width", stroke_width),
+    )
+}
+
 /// chevrons
 ///
 /// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/chevrons.svg)
@@ -131,12 +278,226 @@ pub fn chevrons(
     doc
 }
 
+/// circles bucketed by a grid of cells over a rectangle, with each circle in every cell its disc
+/// overlaps, and distances wrapped around the rectangle when it is toroidal
+struct CircleGrid {
+    origin: (f32, f32),
+    size: (f32, f32),
+    toroidal: bool,
+    cell: (f32, f32),
+    columns: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+    circles: Vec<(f32, f32, f32)>,
+}
+
+impl CircleGrid {
+    fn new(origin: (f32, f32), (width, height): (f32, f32), cell: f32, toroidal: bool) -> Self {
+        let (columns, rows) = (
+            (width / cell).ceil().max(1.0) as usize,
+            (height / cell).ceil().max(1.0) as usize,
+        );
+
+        CircleGrid {
+            origin,
+            size: (width, height),
+            toroidal,
+            cell: (width / columns as f32, height / rows as f32),
+            columns,
+            rows,
+            cells: vec![Vec::new(); columns * rows],
+            circles: Vec::new(),
+        }
+    }
+
+    fn cell_of(&self, (x, y): (f32, f32)) -> (isize, isize) {
+        let (i, j) = (
+            ((x - self.origin.0) / self.cell.0).floor() as isize,
+            ((y - self.origin.1) / self.cell.1).floor() as isize,
+        );
+
+        if self.toroidal {
+            (i, j)
+        } else {
+            (
+                i.clamp(0, self.columns as isize - 1),
+                j.clamp(0, self.rows as isize - 1),
+            )
+        }
+    }
+
+    fn index(&self, (i, j): (isize, isize)) -> usize {
+        j.rem_euclid(self.rows as isize) as usize * self.columns
+            + i.rem_euclid(self.columns as isize) as usize
+    }
+
+    /// `(x, y)` relative to `(ox, oy)`, by the shortest way around when the grid is toroidal
+    fn delta(&self, (x, y): (f32, f32), (ox, oy): (f32, f32)) -> (f32, f32) {
+        let (dx, dy) = (x - ox, y - oy);
+
+        if self.toroidal {
+            (
+                dx - self.size.0 * (dx / self.size.0).round(),
+                dy - self.size.1 * (dy / self.size.1).round(),
+            )
+        } else {
+            (dx, dy)
+        }
+    }
+
+    fn insert(&mut self, (x, y, r): (f32, f32, f32)) {
+        let ix = self.circles.len();
+        self.circles.push((x, y, r));
+
+        let ((x0, y0), (x1, y1)) = (self.cell_of((x - r, y - r)), self.cell_of((x + r, y + r)));
+
+        // a circle wider than the grid would land in the same cells more than once
+        for j in y0..=y1.min(y0 + self.rows as isize - 1) {
+            for i in x0..=x1.min(x0 + self.columns as isize - 1) {
+                let cell = self.index((i, j));
+                self.cells[cell].push(ix);
+            }
+        }
+    }
+
+    /// distance from `(x, y)` to the edge of the nearest circle, negative inside a circle, along
+    /// with the index of that circle, or `limit` when every circle is further away
+    fn clearance(&self, (x, y): (f32, f32), limit: f32) -> (f32, Option<usize>) {
+        let (cx, cy) = self.cell_of((x, y));
+        let (mut best, mut nearest) = (limit, None);
+
+        let cell = self.cell.0.min(self.cell.1);
+        let (columns, rows) = (self.columns as isize, self.rows as isize);
+
+        // rings of cells around the cell of the point, until they are further than the best
+        for k in 0..columns.max(rows) {
+            if (k - 1) as f32 * cell >= best {
+                break;
+            }
+
+            let (j0, j1, i0, i1) = if self.toroidal {
+                (cy - k, cy + k, cx - k, cx + k)
+            } else {
+                (
+                    (cy - k).max(0),
+                    (cy + k).min(rows - 1),
+                    (cx - k).max(0),
+                    (cx + k).min(columns - 1),
+                )
+            };
+
+            for j in j0..=j1 {
+                for i in i0..=i1 {
+                    if (i - cx).abs() != k && (j - cy).abs() != k {
+                        continue;
+                    }
+
+                    for ix in self.cells[self.index((i, j))].iter() {
+                        let (ox, oy, r) = self.circles[*ix];
+                        let (dx, dy) = self.delta((x, y), (ox, oy));
+                        let d = (dx * dx + dy * dy).sqrt() - r;
+
+                        if d < best {
+                            best = d;
+                            nearest = Some(*ix);
+                        }
+                    }
+                }
+            }
+        }
+
+        (best, nearest)
+    }
+}
+
+/// enum to identify the region `circle_packing_in` packs circles into
+#[derive(Clone, Copy)]
+pub enum CirclePackingBounds<'a> {
+    /// the rectangle of the pattern, with every circle inside it
+    Rectangle,
+    /// the rectangle of the pattern, with circles wrapping around its edges so the pattern tiles
+    Toroidal,
+    /// the inside of a polygon, given by its vertices
+    Polygon(&'a [(f32, f32)]),
+    /// the points for which the function returns `true`, with the room around each center found
+    /// by sampling it, so thin features of the mask can be missed
+    Mask(&'a dyn Fn(f32, f32) -> bool),
+}
+
+/// distance from `(x, y)` to the edges of the polygon, negative outside it
+fn polygon_clearance((x, y): (f32, f32), polygon: &[(f32, f32)]) -> f32 {
+    let mut inside = false;
+    let mut distance = f32::MAX;
+
+    for k in 0..polygon.len() {
+        let ((ax, ay), (bx, by)) = (polygon[k], polygon[(k + 1) % polygon.len()]);
+
+        if (ay > y) != (by > y) && x < ax + (y - ay) / (by - ay) * (bx - ax) {
+            inside = !inside;
+        }
+
+        let (ex, ey) = (bx - ax, by - ay);
+        let t = (((x - ax) * ex + (y - ay) * ey) / (ex * ex + ey * ey)).clamp(0.0, 1.0);
+        distance = distance.min(((x - ax - ex * t).powi(2) + (y - ay - ey * t).powi(2)).sqrt());
+    }
+
+    if inside {
+        distance
+    } else {
+        -distance
+    }
+}
+
+/// the largest radius up to `limit` for which points around `(x, y)` fall inside `mask`
+fn mask_clearance((x, y): (f32, f32), mask: &dyn Fn(f32, f32) -> bool, limit: f32) -> f32 {
+    if !mask(x, y) {
+        return -1.0;
+    }
+
+    let fits = |r: f32| {
+        (1..=3).all(|ring| {
+            let n = ring * 8;
+
+            (0..n).all(|k| {
+                let (sin, cos) = (k as f32 / n as f32 * std::f32::consts::PI * 2.0).sin_cos();
+                mask(
+                    x + cos * r * ring as f32 / 3.0,
+                    y + sin * r * ring as f32 / 3.0,
+                )
+            })
+        })
+    };
+
+    if fits(limit) {
+        return limit;
+    }
+
+    let (mut lo, mut hi) = (0.0, limit);
+
+    for _ in 0..16 {
+        let mid = (lo + hi) / 2.0;
+
+        if fits(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
 /// circle packing
 ///
 /// https://generativeartistry.com/tutorials/circle-packing/
 ///
 /// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/circle_packing.svg)
 ///
+/// Each center in turn gets the largest circle, up to `maxr`, that fits inside the pattern
+/// without overlapping the circles before it. Centers with less than `minr` of room are skipped.
+///
+/// See `circle_packing_in` for other bounds and for nested circles.
+///
 /// ```
 /// use geopattern::circle_packing;
 ///
@@ -165,72 +526,174 @@ pub fn circle_packing(
     fill: &[(&str, f32)],
     stroke: (&str, f32, f32),
     background_color: &str,
+) -> Document {
+    circle_packing_in(
+        CirclePackingBounds::Rectangle,
+        centers,
+        (minr, maxr),
+        (width, height),
+        (0, 0.0),
+        fill,
+        stroke,
+        background_color,
+    )
+}
+
+/// circle packing, inside other bounds and inside the circles themselves
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/circle_packing_in.svg)
+///
+/// Packs circles as `circle_packing` does, inside `bounds`. `Toroidal` bounds wrap the circles
+/// around the edges of the pattern, so it tiles, and limit `maxr` to half its shorter side.
+///
+/// With `(depth, gap)`, the centers that fall inside a circle are packed inside that circle, at
+/// least `gap` from its edge, down to `depth` levels of circles within circles. The inner circles
+/// are drawn over the outer ones.
+///
+/// ```
+/// use geopattern::{circle_packing_in, CirclePackingBounds};
+///
+/// let c = circle_packing_in(
+///     CirclePackingBounds::Polygon(&[(100.0, 0.0), (200.0, 200.0), (0.0, 200.0)]),
+///     &(0..400)
+///         .map(|ix| ((ix * 37 % 200) as f32, (ix * 91 % 200) as f32))
+///         .collect::<Vec<(f32, f32)>>(),
+///     (0.5, 24.0),
+///     (200.0, 200.0),
+///     (2, 1.0),
+///     &(0..400)
+///         .map(|v| (if v & 1 == 0 { "#222" } else { "#ddd" }, 0.5))
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#ddd", 1.0, 0.2),
+///     "#EEE",
+/// );
+///
+/// println!("{}", c);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn circle_packing_in(
+    bounds: CirclePackingBounds,
+    centers: &[(f32, f32)],
+    (minr, maxr): (f32, f32),
+    (width, height): (f32, f32),
+    (depth, gap): (usize, f32),
+    fill: &[(&str, f32)],
+    stroke: (&str, f32, f32),
+    background_color: &str,
 ) -> Document {
     debug_assert_eq!(centers.len(), fill.len());
 
-    let mut doc = create_document((width, height), background_color);
+    let toroidal = matches!(bounds, CirclePackingBounds::Toroidal);
+    let maxr = if toroidal {
+        maxr.min(width.min(height) / 2.0)
+    } else {
+        maxr
+    };
 
-    let mut circles: Vec<(f32, f32, f32)> = Vec::new();
+    // `(parent, centers)` to pack, each center as an index along with its position, which is
+    // moved next to the parent when it only lands inside it by wrapping around the edges
+    let mut regions = vec![(
+        None::<(f32, f32, f32)>,
+        centers
+            .iter()
+            .copied()
+            .enumerate()
+            .collect::<Vec<(usize, (f32, f32))>>(),
+    )];
+    let mut circles: Vec<(usize, (f32, f32, f32))> = Vec::new();
+
+    for level in 0..=depth {
+        let mut inner = Vec::new();
+
+        for (parent, candidates) in regions {
+            let ((x0, y0), (w, h)) = match parent {
+                Some((cx, cy, r)) => ((cx - r, cy - r), (r * 2.0, r * 2.0)),
+                None => ((0.0, 0.0), (width, height)),
+            };
 
-    // explicit immutable borrow to avoid collision with mutable borrow in loop
-    // https://stackoverflow.com/a/57690260
-    let has_collision = |(x, y, r): (f32, f32, f32), circles: &Vec<(f32, f32, f32)>| {
-        if x + r > width || x - r < 0.0 {
-            return true;
-        }
+            // about one center per cell
+            let cell = ((w * h) / candidates.len().max(1) as f32).sqrt().max(minr);
+            let mut grid = CircleGrid::new((x0, y0), (w, h), cell, toroidal && parent.is_none());
+            let mut queued = Vec::new();
 
-        if y + r > height || y - r < 0.0 {
-            return true;
-        }
+            for (i, (x, y)) in candidates {
+                let limit = match (parent, bounds) {
+                    (Some((cx, cy, r)), _) => {
+                        r - gap - ((x - cx).powi(2) + (y - cy).powi(2)).sqrt()
+                    }
+                    (None, CirclePackingBounds::Rectangle) => {
+                        x.min(width - x).min(y).min(height - y)
+                    }
+                    (None, CirclePackingBounds::Toroidal) => maxr,
+                    (None, CirclePackingBounds::Polygon(polygon)) => {
+                        polygon_clearance((x, y), polygon)
+                    }
+                    (None, CirclePackingBounds::Mask(mask)) => mask_clearance((x, y), mask, maxr),
+                };
 
-        for c in circles.iter() {
-            let a = r + c.2;
-            let dx = x - c.0;
-            let dy = y - c.1;
+                let (r, nearest) = grid.clearance((x, y), maxr.min(limit));
 
-            if a * a >= dx * dx + dy * dy {
-                return true;
-            }
-        }
+                if r >= minr {
+                    grid.insert((x, y, r));
+                    circles.push((i, (x, y, r)));
+                } else if let (Some(ix), true) = (nearest, r < 0.0 && level < depth) {
+                    let (ox, oy, _) = grid.circles[ix];
+                    let (dx, dy) = grid.delta((x, y), (ox, oy));
 
-        false
-    };
+                    queued.push((ix, (i, (ox + dx, oy + dy))));
+                }
+            }
 
-    let fit_radius = |(x, y): (f32, f32), circles: &Vec<(f32, f32, f32)>| {
-        let mut r = minr;
+            for (ix, circle) in grid.circles.iter().enumerate() {
+                let candidates = queued
+                    .iter()
+                    .filter(|(parent, _)| *parent == ix)
+                    .map(|(_, candidate)| *candidate)
+                    .collect::<Vec<(usize, (f32, f32))>>();
 
-        while r < maxr {
-            if has_collision((x, y, r), &circles) {
-                r -= 1.0;
-                break;
+                if !candidates.is_empty() {
+                    inner.push((Some(*circle), candidates));
+                }
             }
-            r += 1.0;
         }
 
-        r
-    };
+        regions = inner;
+    }
 
-    for (i, (x, y)) in centers.iter().enumerate() {
-        let (x, y) = (*x, *y);
+    let mut doc = create_document((width, height), background_color);
 
-        if has_collision((x, y, minr), &circles) {
-            continue;
+    for (i, (x, y, r)) in circles {
+        // the copies of a circle across the edges it overlaps
+        let offsets = if toroidal {
+            let xs = [-width, 0.0, width];
+            let ys = [-height, 0.0, height];
+
+            xs.iter()
+                .flat_map(|dx| ys.iter().map(move |dy| (*dx, *dy)))
+                .filter(|(dx, dy)| {
+                    x + dx + r > 0.0
+                        && x + dx - r < width
+                        && y + dy + r > 0.0
+                        && y + dy - r < height
+                })
+                .collect::<Vec<(f32, f32)>>()
+        } else {
+            vec![(0.0, 0.0)]
+        };
+
+        for (dx, dy) in offsets {
+            doc = doc.add(
+                Circle::new()
+                    .set("cx", x + dx)
+                    .set("cy", y + dy)
+                    .set("r", r)
+                    .set("fill", fill[i].0)
+                    .set("fill-opacity", fill[i].1)
+                    .set("stroke", stroke.0)
+                    .set("stroke-width", stroke.1)
+                    .set("stroke-opacity", stroke.2),
+            );
         }
-
-        let r = fit_radius((x, y), &circles);
-
-        circles.push((x, y, r));
-        doc = doc.add(
-            Circle::new()
-                .set("cx", x)
-                .set("cy", y)
-                .set("r", r)
-                .set("fill", fill[i].0)
-                .set("fill-opacity", fill[i].1)
-                .set("stroke", stroke.0)
-                .set("stroke-width", stroke.1)
-                .set("stroke-opacity", stroke.2),
-        );
     }
 
     doc
@@ -321,80 +784,429 @@ pub fn concentric_circles(
     doc
 }
 
-/// Cubic Disarray
+/// Contours
 ///
-/// https://generativeartistry.com/tutorials/cubic-disarray/
+/// https://en.wikipedia.org/wiki/Marching_squares
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/cubic_disarray.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/contours.svg)
+///
+/// Samples `noise` on a grid of `cell_size` squares, repeating with the size of the pattern so
+/// the lines tile seamlessly, and traces `stroke.len()` iso-lines spaced evenly between the
+/// lowest and highest samples. The lines are smoothed through the midpoints of their segments.
+///
+/// `fill`, when not empty, has one colour more than `stroke`, and fills the bands below,
+/// between and above the lines, from the lowest to the highest.
 ///
 /// ```
-/// use geopattern::cubic_disarray;
+/// use geopattern::contours;
+/// use geopattern::noise::{Noise, NoiseKind};
 ///
-/// let c = cubic_disarray(
-///     60.0,
-///     (2, 2),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     ("#ddd", 0.2),
-///     (
-///         &(0..4).map(|v| 0.02 + (v as f32) / 4.0).collect::<Vec<f32>>(),
-///         &(0..4).map(|v| 0.02 + (v as f32 * std::f32::consts::PI) / 4.0).collect::<Vec<f32>>(),
-///     ),
+/// let c = contours(
+///     (200.0, 200.0),
+///     &Noise::new(NoiseKind::Perlin, 7, 0.01),
+///     5.0,
+///     &[("#222", 0.8), ("#444", 0.8), ("#666", 0.8)],
+///     1.5,
+///     &[("#ddd", 0.1), ("#ddd", 0.2), ("#ddd", 0.3), ("#ddd", 0.4)],
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn cubic_disarray(
-    side: f32,
-    (width, height): (usize, usize),
+pub fn contours(
+    (width, height): (f32, f32),
+    noise: &Noise,
+    cell_size: f32,
+    stroke: &[(&str, f32)],
+    stroke_width: f32,
     fill: &[(&str, f32)],
-    stroke: (&str, f32),
-    (translate, rotate): (&[f32], &[f32]),
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(fill.len(), width * height);
-    debug_assert_eq!(translate.len(), width * height);
-    debug_assert_eq!(rotate.len(), width * height);
+    debug_assert!(fill.is_empty() || fill.len() == stroke.len() + 1);
 
-    let mut doc = create_document(
-        (side * width as f32, side * height as f32),
-        background_color,
+    let noise = Noise {
+        period: Some((width, height)),
+        ..*noise
+    };
+
+    let (columns, rows) = (
+        (width / cell_size).round().max(1.0) as usize,
+        (height / cell_size).round().max(1.0) as usize,
     );
+    let (dx, dy) = (width / columns as f32, height / rows as f32);
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+    let samples = (0..rows)
+        .flat_map(|j| (0..columns).map(move |i| (i as f32 * dx, j as f32 * dy)))
+        .map(|(x, y)| noise.get(x, y))
+        .collect::<Vec<f32>>();
 
-            doc = doc.add(
-                Rectangle::new()
-                    .set("x", (x as f32) * side)
-                    .set("y", (y as f32) * side)
-                    .set("width", side)
-                    .set("height", side)
-                    .set("fill", fill[ix].0)
-                    .set("fill-opacity", fill[ix].1)
-                    .set("stroke", stroke.0)
-                    .set("stroke-opacity", stroke.1)
-                    .set(
-                        "transform",
-                        format!(
-                            "translate({} {}) rotate({} {} {})",
-                            translate[ix],
-                            0,
-                            rotate[ix],
-                            (x as f32) * side,
-                            (y as f32) * side
-                        ),
-                    ),
-            );
-        }
+    let value = |i: usize, j: usize| samples[(j % rows) * columns + i % columns];
+
+    let (low, high) = samples
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(a, b), v| (a.min(*v), b.max(*v)));
+
+    let levels = (0..stroke.len())
+        .map(|k| low + (high - low) * (k + 1) as f32 / (stroke.len() + 1) as f32)
+        .collect::<Vec<f32>>();
+
+    // the part of a cell at or above `level`, as a polygon along with, for every point on a side
+    // of the cell, the grid edge it lies on as `(vertical, i, j)`
+    let clip = |i: usize, j: usize, level: f32| {
+        let corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)];
+        let mut polygon = Vec::new();
+
+        for k in 0..4 {
+            let ((ai, aj), (bi, bj)) = (corners[k], corners[(k + 1) % 4]);
+            let (va, vb) = (value(ai, aj), value(bi, bj));
+
+            if va >= level {
+                polygon.push(((ai as f32 * dx, aj as f32 * dy), None));
+            }
+
+            if (va >= level) != (vb >= level) {
+                let t = (level - va) / (vb - va);
+
+                polygon.push((
+                    (
+                        (ai as f32 + (bi as f32 - ai as f32) * t) * dx,
+                        (aj as f32 + (bj as f32 - aj as f32) * t) * dy,
+                    ),
+                    Some((ai == bi, ai.min(bi), aj.min(bj))),
+                ));
+            }
+        }
+
+        polygon
+    };
+
+    let mut doc = create_document((width, height), background_color);
+
+    if !fill.is_empty() {
+        doc = doc.add(
+            Rectangle::new()
+                .set("x", 0)
+                .set("y", 0)
+                .set("width", "100%")
+                .set("height", "100%")
+                .set("fill", fill[0].0)
+                .set("fill-opacity", fill[0].1),
+        );
+    }
+
+    for (k, level) in levels.iter().enumerate() {
+        let mut band = String::new();
+        let mut segments = BTreeMap::new();
+        let mut positions = BTreeMap::new();
+
+        for j in 0..rows {
+            for i in 0..columns {
+                let polygon = clip(i, j, *level);
+
+                if polygon.is_empty() {
+                    continue;
+                }
+
+                band.push_str(&format!(
+                    " M {} Z",
+                    polygon_points(&polygon.iter().map(|(p, _)| *p).collect::<Vec<(f32, f32)>>())
+                ));
+
+                // consecutive points on two sides of the cell are joined by the iso-line
+                for m in 0..polygon.len() {
+                    let (a, b) = (polygon[m], polygon[(m + 1) % polygon.len()]);
+
+                    if let (Some(ka), Some(kb)) = (a.1, b.1) {
+                        segments.insert(ka, kb);
+                        positions.insert(ka, a.0);
+                        positions.insert(kb, b.0);
+                    }
+                }
+            }
+        }
+
+        if !fill.is_empty() {
+            doc = doc.add(
+                Path::new()
+                    .set("d", band.trim_start())
+                    .set("fill", fill[k + 1].0)
+                    .set("fill-opacity", fill[k + 1].1),
+            );
+        }
+
+        // lines that cross the edges of the tile end there, and continue from the opposite edge
+        let ends = segments
+            .values()
+            .copied()
+            .collect::<BTreeSet<(bool, usize, usize)>>();
+        let mut starts = segments
+            .keys()
+            .filter(|key| !ends.contains(key))
+            .copied()
+            .collect::<Vec<(bool, usize, usize)>>();
+
+        let mut path = String::new();
+
+        loop {
+            let start = match starts.pop() {
+                Some(start) => start,
+                None => match segments.keys().next() {
+                    Some(start) => *start,
+                    None => break,
+                },
+            };
+
+            let mut points = vec![positions[&start]];
+            let mut key = start;
+
+            while let Some(next) = segments.remove(&key) {
+                key = next;
+                points.push(positions[&key]);
+            }
+
+            let n = points.len();
+            let mid = |a: (f32, f32), b: (f32, f32)| ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+
+            if key == start {
+                // a closed loop, the last point repeats the first
+                let start = mid(points[n - 2], points[0]);
+                path.push_str(&format!(" M {} {}", start.0, start.1));
+
+                for m in 0..n - 1 {
+                    let (p, q) = (points[m], mid(points[m], points[m + 1]));
+                    path.push_str(&format!(" Q {} {} {} {}", p.0, p.1, q.0, q.1));
+                }
+
+                path.push_str(" Z");
+            } else {
+                path.push_str(&format!(" M {} {}", points[0].0, points[0].1));
+
+                for m in 1..n - 1 {
+                    let (p, q) = (points[m], mid(points[m], points[m + 1]));
+
+                    if m == 1 {
+                        let start = mid(points[0], points[1]);
+                        path.push_str(&format!(" L {} {}", start.0, start.1));
+                    }
+
+                    path.push_str(&format!(" Q {} {} {} {}", p.0, p.1, q.0, q.1));
+                }
+
+                path.push_str(&format!(" L {} {}", points[n - 1].0, points[n - 1].1));
+            }
+        }
+
+        doc = doc.add(
+            Path::new()
+                .set("d", path.trim_start())
+                .set("fill", "none")
+                .set("stroke", stroke[k].0)
+                .set("stroke-opacity", stroke[k].1)
+                .set("stroke-width", stroke_width)
+                .set("stroke-linecap", "round")
+                .set("stroke-linejoin", "round"),
+        );
+    }
+
+    doc
+}
+
+/// Cubic Disarray
+///
+/// https://generativeartistry.com/tutorials/cubic-disarray/
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/cubic_disarray.svg)
+///
+/// ```
+/// use geopattern::cubic_disarray;
+///
+/// let c = cubic_disarray(
+///     60.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#ddd", 0.2),
+///     (
+///         &(0..4).map(|v| 0.02 + (v as f32) / 4.0).collect::<Vec<f32>>(),
+///         &(0..4).map(|v| 0.02 + (v as f32 * std::f32::consts::PI) / 4.0).collect::<Vec<f32>>(),
+///     ),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn cubic_disarray(
+    side: f32,
+    (width, height): (usize, usize),
+    fill: &[(&str, f32)],
+    stroke: (&str, f32),
+    (translate, rotate): (&[f32], &[f32]),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(translate.len(), width * height);
+
+    cubic_disarray_with(
+        side,
+        (width, height),
+        CubicDisarrayShape::Square,
+        &CubicDisarrayOffsets::Explicit(
+            &translate
+                .iter()
+                .map(|t| (*t, 0.0))
+                .collect::<Vec<(f32, f32)>>(),
+            rotate,
+        ),
+        fill,
+        stroke,
+        background_color,
+    )
+}
+
+/// enum to identify the shape drawn in each cell of `cubic_disarray_with`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CubicDisarrayShape {
+    /// squares filling the cells, as in `cubic_disarray`
+    Square,
+    /// circles touching the edges of the cells
+    Circle,
+    /// flat topped hexagons touching the middles of the left and right edges of the cells
+    Hexagon,
+}
+
+/// enum to identify how `cubic_disarray_with` moves each cell
+#[derive(Clone, Copy, Debug)]
+pub enum CubicDisarrayOffsets<'a> {
+    /// `(x, y)` translations and rotations in degrees, one for each cell
+    Explicit(&'a [(f32, f32)], &'a [f32]),
+    /// `(seed, (max_translate_x, max_translate_y), max_rotate)`, random translations and
+    /// rotations in degrees that grow from nothing in the first row to their maximum in the
+    /// last, as in the tutorial
+    Progressive(u64, (f32, f32), f32),
+}
+
+/// Cubic Disarray, with a choice of shape and 2-D offsets
+///
+/// https://generativeartistry.com/tutorials/cubic-disarray/
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/cubic_disarray_with.svg)
+///
+/// Each shape is translated, then rotated about the top left corner of its cell.
+///
+/// ```
+/// use geopattern::{cubic_disarray_with, CubicDisarrayOffsets, CubicDisarrayShape};
+///
+/// let c = cubic_disarray_with(
+///     30.0,
+///     (4, 4),
+///     CubicDisarrayShape::Hexagon,
+///     &CubicDisarrayOffsets::Progressive(7, (6.0, 3.0), 20.0),
+///     &(0..16).map(|_| ("#ddd", 0.8)).collect::<Vec<(&str, f32)>>(),
+///     ("#222", 1.0),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn cubic_disarray_with(
+    side: f32,
+    (width, height): (usize, usize),
+    shape: CubicDisarrayShape,
+    offsets: &CubicDisarrayOffsets,
+    fill: &[(&str, f32)],
+    stroke: (&str, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(fill.len(), width * height);
+
+    let (translate, rotate) = match *offsets {
+        CubicDisarrayOffsets::Explicit(translate, rotate) => (translate.to_vec(), rotate.to_vec()),
+        CubicDisarrayOffsets::Progressive(seed, (max_x, max_y), max_rotate) => {
+            let mut rng = Rng::new(seed);
+            let mut between = |limit: f32| limit * (rng.next_f32() * 2.0 - 1.0);
+
+            (0..width * height)
+                .map(|ix| {
+                    let progress = (ix / width) as f32 / (height.max(2) - 1) as f32;
+
+                    let translate = (between(max_x) * progress, between(max_y) * progress);
+                    (translate, between(max_rotate) * progress)
+                })
+                .unzip()
+        }
+    };
+
+    debug_assert_eq!(translate.len(), width * height);
+    debug_assert_eq!(rotate.len(), width * height);
+
+    let mut doc = create_document(
+        (side * width as f32, side * height as f32),
+        background_color,
+    );
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            let (x, y) = ((x as f32) * side, (y as f32) * side);
+
+            let transform = format!(
+                "translate({} {}) rotate({} {} {})",
+                translate[ix].0, translate[ix].1, rotate[ix], x, y
+            );
+
+            doc = match shape {
+                CubicDisarrayShape::Square => doc.add(
+                    Rectangle::new()
+                        .set("x", x)
+                        .set("y", y)
+                        .set("width", side)
+                        .set("height", side)
+                        .set("fill", fill[ix].0)
+                        .set("fill-opacity", fill[ix].1)
+                        .set("stroke", stroke.0)
+                        .set("stroke-opacity", stroke.1)
+                        .set("transform", transform),
+                ),
+                CubicDisarrayShape::Circle => doc.add(
+                    Circle::new()
+                        .set("cx", x + side / 2.0)
+                        .set("cy", y + side / 2.0)
+                        .set("r", side / 2.0)
+                        .set("fill", fill[ix].0)
+                        .set("fill-opacity", fill[ix].1)
+                        .set("stroke", stroke.0)
+                        .set("stroke-opacity", stroke.1)
+                        .set("transform", transform),
+                ),
+                CubicDisarrayShape::Hexagon => {
+                    let points = (0..6)
+                        .map(|k| {
+                            let a = k as f32 * std::f32::consts::FRAC_PI_3;
+                            (
+                                x + side / 2.0 * (1.0 + a.cos()),
+                                y + side / 2.0 * (1.0 + a.sin()),
+                            )
+                        })
+                        .collect::<Vec<(f32, f32)>>();
+
+                    doc.add(
+                        Polyline::new()
+                            .set("points", polygon_points(&points))
+                            .set("fill", fill[ix].0)
+                            .set("fill-opacity", fill[ix].1)
+                            .set("stroke", stroke.0)
+                            .set("stroke-opacity", stroke.1)
+                            .set("transform", transform),
+                    )
+                }
+            };
+        }
     }
 
     doc
@@ -516,603 +1328,592 @@ pub fn diamonds(
     doc
 }
 
-/// hexagons
+/// enum to identify how `flow_field` turns noise into directions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlowFieldNoise {
+    /// the noise value is the angle of the flow, in turns
+    Perlin,
+    /// the flow follows the contours of the noise, so it has no sources or sinks
+    Curl,
+}
+
+/// Flow Field
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/hexagons.svg)
+/// https://tylerxhobbs.com/essays/2020/flow-fields
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/flow_field.svg)
+///
+/// The field is sampled from Perlin noise generated from `seed`, with `frequency` noise cells
+/// per pixel. Streamlines are traced through it from the center outwards, each starting
+/// `separation` away from the ones before it and ending when it comes within half of that of
+/// another line or leaves the pattern.
+///
+/// Line `i` is filled with `fill[i % fill.len()]`, and is `line_width` wide in the middle,
+/// narrowing by `taper` towards its ends, from `0.0` for even lines to `1.0` for points.
 ///
 /// ```
-/// use geopattern::hexagons;
+/// use geopattern::{flow_field, FlowFieldNoise};
 ///
-/// let c = hexagons(
-///     20.0,
-///     (2, 2),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     ("#ddd", 0.2),
+/// let c = flow_field(
+///     (200.0, 200.0),
+///     FlowFieldNoise::Curl,
+///     (7, 0.01),
+///     10.0,
+///     &[("#222", 0.8), ("#ddd", 0.6)],
+///     (3.0, 0.8),
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn hexagons(
-    side: f32,
-    (width, height): (usize, usize),
+pub fn flow_field(
+    (width, height): (f32, f32),
+    noise: FlowFieldNoise,
+    (seed, frequency): (u64, f32),
+    separation: f32,
     fill: &[(&str, f32)],
-    stroke: (&str, f32),
+    (line_width, taper): (f32, f32),
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(fill.len(), width * height);
+    debug_assert!(!fill.is_empty());
+    debug_assert!(separation > 0.0);
 
-    let hexagon_width = side * 2.0;
-    let hexagon_height = side * (3.0 as f32).sqrt();
+    let perlin = Noise::new(NoiseKind::Perlin, seed, frequency);
 
-    let a = side / 2.0;
-    let b = (std::f32::consts::PI / 3.0).sin() * side;
-    let points = format!(
-        "0,{},{},0,{},0,{},{},{},{},{},{},0,{}",
-        b,
-        a,
-        a + side,
-        2.0 * side,
-        b,
-        a + side,
-        2.0 * b,
-        a,
-        2.0 * b,
-        b
+    let direction = |(x, y): (f32, f32)| {
+        let (dx, dy) = match noise {
+            FlowFieldNoise::Perlin => {
+                let angle = perlin.get(x, y) * std::f32::consts::PI * 2.0;
+                (angle.cos(), angle.sin())
+            }
+            FlowFieldNoise::Curl => {
+                let e = 0.01 / frequency;
+
+                (
+                    perlin.get(x, y + e) - perlin.get(x, y - e),
+                    perlin.get(x - e, y) - perlin.get(x + e, y),
+                )
+            }
+        };
+
+        let length = (dx * dx + dy * dy).sqrt();
+
+        if length > 1e-9 {
+            Some((dx / length, dy / length))
+        } else {
+            None
+        }
+    };
+
+    let step = separation / 4.0;
+    let test = separation / 2.0;
+    let max_steps = ((width + height) * 4.0 / step) as usize;
+
+    // the points of the finished lines, bucketed by `separation`
+    let (columns, rows) = (
+        (width / separation).ceil() as usize + 1,
+        (height / separation).ceil() as usize + 1,
     );
+    let mut grid = vec![Vec::new(); columns * rows];
 
-    let mut doc = create_document(
+    let cell = |(x, y): (f32, f32)| {
         (
-            (hexagon_width + side) * width as f32 / 2.0,
-            hexagon_height * height as f32,
-        ),
-        background_color,
-    );
+            ((x / separation) as usize).min(columns - 1),
+            ((y / separation) as usize).min(rows - 1),
+        )
+    };
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+    let is_free = |grid: &[Vec<(f32, f32)>], p: (f32, f32), distance: f32| {
+        let (cx, cy) = cell(p);
 
-            let polyline = Polyline::new()
-                .set("points", points.as_str())
-                .set("fill", fill[ix].0)
-                .set("fill-opacity", fill[ix].1)
-                .set("stroke", stroke.0)
-                .set("stroke-opacity", stroke.1);
+        (cy.saturating_sub(1)..(cy + 2).min(rows)).all(|y| {
+            (cx.saturating_sub(1)..(cx + 2).min(columns)).all(|x| {
+                grid[y * columns + x]
+                    .iter()
+                    .all(|q| (p.0 - q.0).powi(2) + (p.1 - q.1).powi(2) >= distance * distance)
+            })
+        })
+    };
 
-            let dy = match x % 2 {
-                1 => (y as f32) * hexagon_height + hexagon_height / 2.0,
-                _ => (y as f32) * hexagon_height,
-            };
+    let inside = |(x, y): (f32, f32)| x >= 0.0 && x <= width && y >= 0.0 && y <= height;
 
-            doc = doc.add(polyline.clone().set(
-                "transform",
-                format!(
-                    "translate({}, {})",
-                    (x as f32) * side * 1.5 - hexagon_width / 2.0,
-                    dy - hexagon_height / 2.0
-                ),
-            ));
+    let trace = |grid: &[Vec<(f32, f32)>], start: (f32, f32), sign: f32| {
+        let mut points = vec![start];
+        // points closer than this along the line are too near to count as a collision
+        let skip = (test * 2.0 / step) as usize + 1;
 
-            if x == 0 {
-                doc = doc.add(polyline.clone().set(
-                    "transform",
-                    format!(
-                        "translate({}, {})",
-                        width as f32 * side * 1.5 - hexagon_width / 2.0,
-                        dy - hexagon_height / 2.0
-                    ),
-                ));
-            }
+        for _ in 0..max_steps {
+            let p = *points.last().unwrap();
 
-            if y == 0 {
-                let dy = match x % 2 {
-                    1 => height as f32 * hexagon_height + hexagon_height / 2.0,
-                    _ => height as f32 * hexagon_height,
-                };
+            // midpoint integration
+            let next = direction(p)
+                .map(|(dx, dy)| (p.0 + dx * sign * step / 2.0, p.1 + dy * sign * step / 2.0))
+                .and_then(direction)
+                .map(|(dx, dy)| (p.0 + dx * sign * step, p.1 + dy * sign * step));
 
-                doc = doc.add(polyline.clone().set(
-                    "transform",
-                    format!(
-                        "translate({}, {})",
-                        (x as f32) * side * 1.5 - hexagon_width / 2.0,
-                        dy - hexagon_height / 2.0
-                    ),
-                ));
-            }
+            let next = match next {
+                Some(next) if inside(next) && is_free(grid, next, test) => next,
+                _ => break,
+            };
 
-            if x == 0 && y == 0 {
-                doc = doc.add(polyline.clone().set(
-                    "transform",
-                    format!(
-                        "translate({}, {})",
-                        width as f32 * side * 1.5 - hexagon_width / 2.0,
-                        5.0 * hexagon_height + hexagon_height / 2.0
-                    ),
-                ));
+            let n = points.len();
+            if n > skip
+                && points[..n - skip]
+                    .iter()
+                    .any(|q| (next.0 - q.0).powi(2) + (next.1 - q.1).powi(2) < test * test)
+            {
+                break;
             }
+
+            points.push(next);
         }
-    }
 
-    doc
-}
+        points
+    };
 
-/// Hypnotic Squares
-///
-/// https://generativeartistry.com/tutorials/hypnotic-squares/
-///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/hypnotic_squares.svg)
-///
-/// ```
-/// use geopattern::hypnotic_squares;
-///
-/// let c = hypnotic_squares(
-///     60.0,
-///     30.0,
-///     5,
-///     (2, 2),
-///     &(0..4)
-///         .map(|i| {
-///             (
-///                 (i % 3) as isize - 1,
-///                 (2 * i % 3) as isize - 1,
-///             )
-///         })
-///         .collect::<Vec<(isize, isize)>>(),
-///     &(0..4)
-///         .map(|v| ("#222", 1.0, 1.0))
-///         .collect::<Vec<(&str, f32, f32)>>(),
-///     "#987987",
-/// );
-///
-/// println!("{}", c);
-/// ```
-pub fn hypnotic_squares(
-    side: f32,
-    min_side: f32,
-    steps: usize,
-    (width, height): (usize, usize),
-    directions: &[(isize, isize)],
-    stroke: &[(&str, f32, f32)],
-    background_color: &str,
-) -> Document {
-    debug_assert!(min_side < side);
-    debug_assert_eq!(stroke.len(), width * height);
-    debug_assert_eq!(directions.len(), width * height);
+    let mut lines: Vec<Vec<(f32, f32)>> = Vec::new();
 
-    let mut doc = create_document(
-        (side * width as f32, side * height as f32),
-        background_color,
-    );
+    let mut seeds = std::collections::VecDeque::new();
+    seeds.push_back((width / 2.0, height / 2.0));
 
-    let step_size = (side - min_side) / steps as f32;
+    // once the lines run out of room for neighbours, the rest of the pattern is searched in order
+    let mut fallback = (0..rows).flat_map(|y| {
+        (0..columns).map(move |x| ((x as f32 + 0.5) * separation, (y as f32 + 0.5) * separation))
+    });
 
-    let create_group = |stroke, stroke_width, stroke_opacity, xdir, ydir| {
-        let mut g = Group::new();
+    loop {
+        let seed = match seeds.pop_front() {
+            Some(seed) => seed,
+            None => match fallback.next() {
+                Some(seed) => seed,
+                None => break,
+            },
+        };
 
-        for i in 0..steps {
-            g = g.add(
-                Rectangle::new()
-                    .set(
-                        "x",
-                        step_size * i as f32 + step_size / 2.0 * i as f32 * xdir as f32,
-                    )
-                    .set(
-                        "y",
-                        step_size * i as f32 + step_size / 2.0 * i as f32 * ydir as f32,
-                    )
-                    .set("width", side - 2.0 * step_size * i as f32)
-                    .set("height", side - 2.0 * step_size * i as f32)
-                    .set("fill", "none")
-                    .set("stroke", stroke)
-                    .set("stroke-width", stroke_width)
-                    .set("stroke-opacity", stroke_opacity),
-            );
+        if !inside(seed) || !is_free(&grid, seed, separation) {
+            continue;
         }
 
-        g
-    };
+        let mut line = trace(&grid, seed, -1.0);
+        line.reverse();
+        line.pop();
+        line.extend(trace(&grid, seed, 1.0));
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+        if line.len() as f32 * step < separation {
+            continue;
+        }
 
-            doc = doc.add(
-                create_group(
-                    stroke[ix].0,
-                    stroke[ix].1,
-                    stroke[ix].2,
-                    directions[ix].0,
-                    directions[ix].1,
-                )
-                .set(
-                    "transform",
-                    format!("translate({} {})", x as f32 * side, y as f32 * side),
-                ),
-            )
+        for i in 0..line.len() {
+            let (p, q) = (line[i.saturating_sub(1)], line[(i + 1).min(line.len() - 1)]);
+            let length = ((q.0 - p.0).powi(2) + (q.1 - p.1).powi(2)).sqrt();
+            let (nx, ny) = (-(q.1 - p.1) / length, (q.0 - p.0) / length);
+            let (x, y) = line[i];
+
+            seeds.push_back((x + nx * separation, y + ny * separation));
+            seeds.push_back((x - nx * separation, y - ny * separation));
         }
+
+        for p in line.iter() {
+            let (cx, cy) = cell(*p);
+            grid[cy * columns + cx].push(*p);
+        }
+
+        lines.push(line);
+    }
+
+    let mut doc = create_document((width, height), background_color);
+
+    for (i, line) in lines.iter().enumerate() {
+        let n = line.len();
+        let (mut left, mut right) = (Vec::with_capacity(n), Vec::with_capacity(n));
+
+        for j in 0..n {
+            let (p, q) = (line[j.saturating_sub(1)], line[(j + 1).min(n - 1)]);
+            let length = ((q.0 - p.0).powi(2) + (q.1 - p.1).powi(2)).sqrt();
+            let (nx, ny) = (-(q.1 - p.1) / length, (q.0 - p.0) / length);
+
+            let t = j as f32 / (n - 1) as f32;
+            let w = line_width / 2.0 * (1.0 - taper + taper * (t * std::f32::consts::PI).sin());
+            let (x, y) = line[j];
+
+            left.push((x + nx * w, y + ny * w));
+            right.push((x - nx * w, y - ny * w));
+        }
+
+        left.extend(right.into_iter().rev());
+
+        let (color, opacity) = fill[i % fill.len()];
+
+        doc = doc.add(
+            Polyline::new()
+                .set("points", polygon_points(&left))
+                .set("fill", color)
+                .set("fill-opacity", opacity),
+        );
     }
 
     doc
 }
 
-/// Joy Division
+/// Gingham
 ///
-/// https://generativeartistry.com/tutorials/joy-division/
+/// https://en.wikipedia.org/wiki/Gingham
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/joy_division.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/gingham.svg)
+///
+/// Horizontal and vertical bands of `size` in a translucent `fill`, separated by bands of the
+/// background, so the checks where they cross come out in a darker shade.
 ///
 /// ```
-/// use geopattern::joy_division;
+/// use geopattern::gingham;
 ///
-/// let c = joy_division(
-///     60.0,
-///     (2, 2),
-///     &(0..4).map(|v| v as f32).collect::<Vec<f32>>(),
-///     ("#333", 0.5, 2.0),
-///     2,
-///     "#987987",
-/// );
+/// let c = gingham(20.0, (2, 2), ("#222", 0.4), "#987987");
 ///
 /// println!("{}", c);
 /// ```
-pub fn joy_division(
-    step_size: f32,
+pub fn gingham(
+    size: f32,
     (width, height): (usize, usize),
-    pulse_heights: &[f32],
-    (stroke_color, stroke_opacity, stroke_width): (&str, f32, f32),
-    padding_top: usize,
+    (fill_color, fill_opacity): (&str, f32),
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(pulse_heights.len(), width * height);
-
     let mut doc = create_document(
-        (
-            width as f32 * step_size,
-            (height + 1 + padding_top) as f32 * step_size,
-        ),
+        (size * 2.0 * width as f32, size * 2.0 * height as f32),
         background_color,
     );
 
     for y in 0..height {
-        let top = (y + 1 + padding_top) as f32 * step_size + stroke_width / 2.0;
-        let mut path = format!("M {} {}", 0, top);
-
-        let (mut pleft, mut ptop) = (0.0, top);
-
-        for x in 0..width {
-            let ix = y * width + x;
-
-            let top = top + pulse_heights[ix];
-            let left = (x + 1) as f32 * step_size;
-
-            path = format!(
-                "{} Q {} {} {} {}",
-                path,
-                pleft,
-                ptop,
-                (left + pleft) / 2.0,
-                (top + ptop) / 2.0,
-            );
-
-            ptop = top;
-            pleft = left;
-        }
-
-        path = format!(
-            "{} Q {} {} {} {}",
-            path,
-            pleft,
-            ptop,
-            width as f32 * step_size,
-            top
+        doc = doc.add(
+            Rectangle::new()
+                .set("x", 0)
+                .set("y", y as f32 * size * 2.0)
+                .set("width", "100%")
+                .set("height", size)
+                .set("fill", fill_color)
+                .set("fill-opacity", fill_opacity),
         );
+    }
 
+    for x in 0..width {
         doc = doc.add(
-            Path::new()
-                .set("d", path)
-                .set("fill", background_color)
-                .set("stroke", stroke_color)
-                .set("stroke-opacity", stroke_opacity)
-                .set("stroke-width", stroke_width),
+            Rectangle::new()
+                .set("x", x as f32 * size * 2.0)
+                .set("y", 0)
+                .set("width", size)
+                .set("height", "100%")
+                .set("fill", fill_color)
+                .set("fill-opacity", fill_opacity),
         );
     }
 
     doc
 }
 
-/// mosaic squares
+/// enum to identify the lattice of the dots in `halftone`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HalftoneLattice {
+    /// dots on a square grid
+    Square,
+    /// dots in rows offset by half a cell, a cell apart in every direction
+    Hexagonal,
+}
+
+/// enum to identify the shape of the dots in `halftone`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HalftoneShape {
+    /// circles, covering the whole pattern at `1.0`
+    Circle,
+    /// squares aligned with the screen
+    Square,
+    /// lines along the rows of the screen, growing thicker
+    Line,
+    /// ellipses stretched along the rows of the screen
+    Ellipse,
+}
+
+/// the values in `[0, 1]` that set the size of the dots in `halftone`
+#[derive(Clone, Copy, Debug)]
+pub enum HalftoneSource<'a> {
+    /// `(values, columns)`, rows of `columns` values stretched over the pattern, each dot taking
+    /// the value it lands on
+    Values(&'a [f32], usize),
+    /// from `0.0` to `1.0` across the pattern, in the direction at this angle in degrees
+    LinearGradient(f32),
+    /// from `1.0` at the center to `0.0` at the corners
+    RadialGradient,
+    /// noise, mapped from `[-1, 1]`
+    Noise(Noise),
+}
+
+/// Halftone
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/mosaic_squares.svg)
+/// https://en.wikipedia.org/wiki/Halftone
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/halftone.svg)
+///
+/// Dots `cell_size` apart on `lattice`, rotated by the screen `angle` in degrees, cover an area
+/// of their cell in proportion to the value of `source` at their centers.
 ///
 /// ```
-/// use geopattern::mosaic_squares;
+/// use geopattern::{halftone, HalftoneLattice, HalftoneShape, HalftoneSource};
 ///
-/// let c = mosaic_squares(
-///     20.0,
-///     (2, 2),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     ("#ddd", 0.2),
+/// let c = halftone(
+///     10.0,
+///     (200.0, 100.0),
+///     (HalftoneLattice::Square, 45.0),
+///     &HalftoneSource::LinearGradient(0.0),
+///     HalftoneShape::Circle,
+///     ("#222", 0.8),
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn mosaic_squares(
-    side: f32,
-    (width, height): (usize, usize),
-    fill_outer: &[(&str, f32)],
-    fill_inner: &[(&str, f32)],
-    stroke: (&str, f32),
+pub fn halftone(
+    cell_size: f32,
+    (width, height): (f32, f32),
+    (lattice, angle): (HalftoneLattice, f32),
+    source: &HalftoneSource,
+    shape: HalftoneShape,
+    (fill_color, fill_opacity): (&str, f32),
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(fill_outer.len(), width * height);
-    debug_assert_eq!(fill_inner.len(), width * height);
+    if let HalftoneSource::Values(values, columns) = source {
+        debug_assert!(*columns > 0 && values.len() >= *columns);
+    }
 
-    let mut doc = create_document(
-        (side * width as f32 * 2.0, side * height as f32 * 2.0),
-        background_color,
-    );
+    let value = |(x, y): (f32, f32)| {
+        let (u, v) = ((x / width).clamp(0.0, 1.0), (y / height).clamp(0.0, 1.0));
 
-    let draw_outer_tile = |mut doc: Document, x: f32, y: f32, ix: usize| {
-        let points = format!("0,0,{},{},0,{},0,0", side, side, side);
+        let value = match source {
+            HalftoneSource::Values(values, columns) => {
+                let rows = values.len() / columns;
+                let (i, j) = (
+                    ((u * *columns as f32) as usize).min(columns - 1),
+                    ((v * rows as f32) as usize).min(rows - 1),
+                );
 
-        let polyline = Polyline::new()
-            .set("points", points)
-            .set("fill", fill_outer[ix].0)
-            .set("fill-opacity", fill_outer[ix].1)
-            .set("stroke", stroke.0)
-            .set("stroke-opacity", stroke.1);
+                values[j * columns + i]
+            }
+            HalftoneSource::LinearGradient(angle) => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let extent = (width * cos).abs() + (height * sin).abs();
 
-        doc = doc.add(polyline.clone().set(
-            "transform",
-            format!("translate({}, {}) scale(1, -1)", x, y + side),
-        ));
-
-        doc = doc.add(polyline.clone().set(
-            "transform",
-            format!("translate({}, {}) scale(-1, -1)", x + side * 2.0, y + side),
-        ));
-
-        doc = doc.add(polyline.clone().set(
-            "transform",
-            format!("translate({}, {}) scale(1, 1)", x, y + side),
-        ));
+                0.5 + ((x - width / 2.0) * cos + (y - height / 2.0) * sin) / extent
+            }
+            HalftoneSource::RadialGradient => {
+                let (dx, dy) = (u - 0.5, v - 0.5);
+                1.0 - (dx * dx + dy * dy).sqrt() / 0.5_f32.sqrt()
+            }
+            HalftoneSource::Noise(noise) => (noise.get(x, y) + 1.0) / 2.0,
+        };
 
-        doc = doc.add(polyline.set(
-            "transform",
-            format!("translate({}, {}) scale(-1, 1)", x + side * 2.0, y + side),
-        ));
+        value.clamp(0.0, 1.0)
+    };
 
-        doc
+    let row_height = match lattice {
+        HalftoneLattice::Square => cell_size,
+        HalftoneLattice::Hexagonal => cell_size * 3.0_f32.sqrt() / 2.0,
     };
 
-    let draw_inner_tile = |mut doc: Document, x: f32, y: f32, ix: usize| {
-        let points = format!("0,0,{},{},0,{},0,0", side, side, side);
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let origin = (cell_size / 2.0, row_height / 2.0);
 
-        let polyline = Polyline::new()
-            .set("points", points.as_str())
-            .set("fill", fill_outer[ix].0)
-            .set("fill-opacity", fill_outer[ix].1)
-            .set("stroke", stroke.0)
-            .set("stroke-opacity", stroke.1);
+    // the lattice rows and columns that land on the pattern, found from its corners in the
+    // coordinates of the screen
+    let (mut min_u, mut max_u, mut min_v, mut max_v) = (f32::MAX, f32::MIN, f32::MAX, f32::MIN);
 
-        doc = doc.add(polyline.clone().set(
-            "transform",
-            format!("translate({}, {}) scale(-1, 1)", x + side, y),
-        ));
+    for (x, y) in [(0.0, 0.0), (width, 0.0), (0.0, height), (width, height)].iter() {
+        let (dx, dy) = (x - origin.0, y - origin.1);
+        let (u, v) = (dx * cos + dy * sin, -dx * sin + dy * cos);
 
-        doc = doc.add(polyline.set(
-            "transform",
-            format!("translate({}, {}) scale(1, -1)", x + side, y + side * 2.0),
-        ));
+        min_u = min_u.min(u);
+        max_u = max_u.max(u);
+        min_v = min_v.min(v);
+        max_v = max_v.max(v);
+    }
 
-        let polyline = Polyline::new()
-            .set("points", points)
-            .set("fill", fill_inner[ix].0)
-            .set("fill-opacity", fill_inner[ix].1)
-            .set("stroke", stroke.0)
-            .set("stroke-opacity", stroke.1);
+    let mut doc = create_document((width, height), background_color);
 
-        doc = doc.add(polyline.clone().set(
-            "transform",
-            format!("translate({}, {}) scale(-1, -1)", x + side, y + side * 2.0),
-        ));
+    for j in (min_v / row_height).floor() as i64 - 1..=(max_v / row_height).ceil() as i64 + 1 {
+        for i in (min_u / cell_size).floor() as i64 - 1..=(max_u / cell_size).ceil() as i64 + 1 {
+            let offset = match lattice {
+                HalftoneLattice::Hexagonal if j.rem_euclid(2) == 1 => cell_size / 2.0,
+                _ => 0.0,
+            };
 
-        doc = doc.add(polyline.set(
-            "transform",
-            format!("translate({}, {}) scale(1, 1)", x + side, y),
-        ));
+            let (u, v) = (i as f32 * cell_size + offset, j as f32 * row_height);
+            let (cx, cy) = (origin.0 + u * cos - v * sin, origin.1 + u * sin + v * cos);
 
-        doc
-    };
+            if cx < -cell_size
+                || cx > width + cell_size
+                || cy < -cell_size
+                || cy > height + cell_size
+            {
+                continue;
+            }
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+            let value = value((cx, cy));
 
-            match x % 2 {
-                1 => match y % 2 {
-                    1 => {
-                        doc = draw_outer_tile(
-                            doc,
-                            (x as f32) * side * 2.0,
-                            (y as f32) * side * 2.0,
-                            ix,
-                        );
-                    }
-                    _ => {
-                        doc = draw_inner_tile(
-                            doc,
-                            (x as f32) * side * 2.0,
-                            (y as f32) * side * 2.0,
-                            ix,
-                        );
-                    }
-                },
-                _ => match y % 2 {
-                    1 => {
-                        doc = draw_inner_tile(
-                            doc,
-                            (x as f32) * side * 2.0,
-                            (y as f32) * side * 2.0,
-                            ix,
-                        );
-                    }
-                    _ => {
-                        doc = draw_outer_tile(
-                            doc,
-                            (x as f32) * side * 2.0,
-                            (y as f32) * side * 2.0,
-                            ix,
-                        );
-                    }
-                },
+            if value <= 0.0 {
+                continue;
             }
+
+            let transform = format!("rotate({} {} {})", angle, cx, cy);
+
+            doc = match shape {
+                HalftoneShape::Circle => doc.add(
+                    Circle::new()
+                        .set("cx", cx)
+                        .set("cy", cy)
+                        .set("r", cell_size * (value / 2.0).sqrt())
+                        .set("fill", fill_color)
+                        .set("fill-opacity", fill_opacity),
+                ),
+                HalftoneShape::Square => {
+                    let side = cell_size * value.sqrt();
+
+                    doc.add(
+                        Rectangle::new()
+                            .set("x", cx - side / 2.0)
+                            .set("y", cy - side / 2.0)
+                            .set("width", side)
+                            .set("height", side)
+                            .set("fill", fill_color)
+                            .set("fill-opacity", fill_opacity)
+                            .set("transform", transform),
+                    )
+                }
+                HalftoneShape::Line => {
+                    let thickness = row_height * value;
+
+                    doc.add(
+                        Rectangle::new()
+                            .set("x", cx - cell_size / 2.0)
+                            .set("y", cy - thickness / 2.0)
+                            .set("width", cell_size)
+                            .set("height", thickness)
+                            .set("fill", fill_color)
+                            .set("fill-opacity", fill_opacity)
+                            .set("transform", transform),
+                    )
+                }
+                HalftoneShape::Ellipse => {
+                    let r = cell_size * (value / 2.0).sqrt();
+
+                    doc.add(
+                        Ellipse::new()
+                            .set("cx", cx)
+                            .set("cy", cy)
+                            .set("rx", r * 1.25)
+                            .set("ry", r / 1.25)
+                            .set("fill", fill_color)
+                            .set("fill-opacity", fill_opacity)
+                            .set("transform", transform),
+                    )
+                }
+            };
         }
     }
 
     doc
 }
 
-/// nested squares
+/// svg path data for the cells of a `columns` by `rows` grid of `unit` squares for which `filled`
+/// holds, with runs of filled cells in a row merged into one rectangle
+fn cell_path<F>(unit: f32, (columns, rows): (usize, usize), filled: F) -> String
+where
+    F: Fn(usize, usize) -> bool,
+{
+    let mut path = String::new();
+
+    for y in 0..rows {
+        let mut x = 0;
+
+        while x < columns {
+            if !filled(x, y) {
+                x += 1;
+                continue;
+            }
+
+            let start = x;
+
+            while x < columns && filled(x, y) {
+                x += 1;
+            }
+
+            path.push_str(&format!(
+                " M {} {} h {} v {} h {} Z",
+                start as f32 * unit,
+                y as f32 * unit,
+                (x - start) as f32 * unit,
+                unit,
+                -((x - start) as f32 * unit),
+            ));
+        }
+    }
+
+    path.trim_start().to_string()
+}
+
+/// Herringbone
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/nested_squares.svg)
+/// https://en.wikipedia.org/wiki/Herringbone_(cloth)
 ///
-/// TODO: make `outer_side` independent of `inner_side`, currently evaluates to `7 * inner_side`
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/herringbone.svg)
+///
+/// A 2/2 twill woven from warp threads in `fill` and weft threads in the background colour, each
+/// thread `unit` wide. The direction of the twill reverses every `stripe` warp threads.
 ///
 /// ```
-/// use geopattern::nested_squares;
+/// use geopattern::herringbone;
 ///
-/// let c = nested_squares(
-///     60.0,
-///     (2, 2),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     "#987987",
-/// );
+/// let c = herringbone(6.0, 6, (2, 4), ("#222", 0.6), "#987987");
 ///
 /// println!("{}", c);
 /// ```
-pub fn nested_squares(
-    inner_side: f32,
+pub fn herringbone(
+    unit: f32,
+    stripe: usize,
     (width, height): (usize, usize),
-    stroke_outer: &[(&str, f32)],
-    fill_inner: &[(&str, f32)],
+    (fill_color, fill_opacity): (&str, f32),
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(fill_inner.len(), width * height);
-    debug_assert_eq!(stroke_outer.len(), width * height);
-
-    let outer_side = inner_side * 7.0;
-
-    let mut doc = create_document(
-        (
-            (inner_side * 2.0 + outer_side) * width as f32,
-            (inner_side * 2.0 + outer_side) * height as f32,
-        ),
-        background_color,
-    );
+    debug_assert!(stripe > 0);
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+    let (columns, rows) = (stripe * 2 * width, 4 * height);
 
-            // outer
-            doc = doc.add(
-                Rectangle::new()
-                    .set(
-                        "x",
-                        (x as f32) * outer_side + (x as f32) * inner_side * 2.0 + inner_side / 2.0,
-                    )
-                    .set(
-                        "y",
-                        (y as f32) * outer_side + (y as f32) * inner_side * 2.0 + inner_side / 2.0,
-                    )
-                    .set("width", outer_side)
-                    .set("height", outer_side)
-                    .set("fill", "none")
-                    .set("stroke", stroke_outer[ix].0)
-                    .set("stroke-width", inner_side)
-                    .set("stroke-opacity", stroke_outer[ix].1),
-            );
+    let path = cell_path(unit, (columns, rows), |x, y| {
+        // the warp shows where it passes over the weft, mirrored in every other stripe
+        let x = match x % (stripe * 2) {
+            x if x < stripe => x,
+            x => stripe * 2 - 1 - x,
+        };
 
-            // inner
-            doc = doc.add(
-                Rectangle::new()
-                    .set(
-                        "x",
-                        (x as f32) * outer_side
-                            + (x as f32) * inner_side * 2.0
-                            + inner_side / 2.0
-                            + inner_side * 2.0,
-                    )
-                    .set(
-                        "y",
-                        (y as f32) * outer_side
-                            + (y as f32) * inner_side * 2.0
-                            + inner_side / 2.0
-                            + inner_side * 2.0,
-                    )
-                    .set("width", inner_side * 3.0)
-                    .set("height", inner_side * 3.0)
-                    .set("fill", "none")
-                    .set("stroke", fill_inner[ix].0)
-                    .set("stroke", fill_inner[ix].0)
-                    .set("stroke-width", inner_side)
-                    .set("stroke-opacity", fill_inner[ix].1),
-            );
-        }
-    }
+        (x + y) % 4 < 2
+    });
 
-    doc
+    create_document(
+        (unit * columns as f32, unit * rows as f32),
+        background_color,
+    )
+    .add(
+        Path::new()
+            .set("d", path)
+            .set("fill", fill_color)
+            .set("fill-opacity", fill_opacity),
+    )
 }
 
-/// octagons
+/// hexagons
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/octagons.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/hexagons.svg)
 ///
 /// ```
-/// use geopattern::octagons;
+/// use geopattern::hexagons;
 ///
-/// let c = octagons(
-///     60.0,
+/// let c = hexagons(
+///     20.0,
 ///     (2, 2),
 ///     &(0..4)
 ///         .map(|v| {
@@ -1128,7 +1929,7 @@ pub fn nested_squares(
 ///
 /// println!("{}", c);
 /// ```
-pub fn octagons(
+pub fn hexagons(
     side: f32,
     (width, height): (usize, usize),
     fill: &[(&str, f32)],
@@ -1137,131 +1938,286 @@ pub fn octagons(
 ) -> Document {
     debug_assert_eq!(fill.len(), width * height);
 
+    let hexagon_width = side * 2.0;
+    let hexagon_height = side * 3.0_f32.sqrt();
+
+    let a = side / 2.0;
+    let b = (std::f32::consts::PI / 3.0).sin() * side;
+    let points = format!(
+        "0,{},{},0,{},0,{},{},{},{},{},{},0,{}",
+        b,
+        a,
+        a + side,
+        2.0 * side,
+        b,
+        a + side,
+        2.0 * b,
+        a,
+        2.0 * b,
+        b
+    );
+
     let mut doc = create_document(
-        (side * width as f32, side * height as f32),
+        (
+            (hexagon_width + side) * width as f32 / 2.0,
+            hexagon_height * height as f32,
+        ),
         background_color,
     );
 
-    let c = 0.33 * side;
-    let points = format!(
-        "{},0,{},0,{},{},{},{},{},{},{},{},0,{},0,{},{},0",
-        c,
-        side - c,
-        side,
-        c,
-        side,
-        side - c,
-        side - c,
-        side,
-        c,
-        side,
-        side - c,
-        c,
-        c
-    );
-
     for y in 0..height {
         for x in 0..width {
             let ix = y * width + x;
 
-            doc = doc.add(
-                Polyline::new()
-                    .set("points", points.as_str())
-                    .set("fill", fill[ix].0)
-                    .set("fill-opacity", fill[ix].1)
-                    .set("stroke", stroke.0)
-                    .set("stroke-opacity", stroke.1)
-                    .set(
-                        "transform",
-                        format!("translate({}, {})", (x as f32) * side, (y as f32) * side),
+            let polyline = Polyline::new()
+                .set("points", points.as_str())
+                .set("fill", fill[ix].0)
+                .set("fill-opacity", fill[ix].1)
+                .set("stroke", stroke.0)
+                .set("stroke-opacity", stroke.1);
+
+            let dy = match x % 2 {
+                1 => (y as f32) * hexagon_height + hexagon_height / 2.0,
+                _ => (y as f32) * hexagon_height,
+            };
+
+            doc = doc.add(polyline.clone().set(
+                "transform",
+                format!(
+                    "translate({}, {})",
+                    (x as f32) * side * 1.5 - hexagon_width / 2.0,
+                    dy - hexagon_height / 2.0
+                ),
+            ));
+
+            if x == 0 {
+                doc = doc.add(polyline.clone().set(
+                    "transform",
+                    format!(
+                        "translate({}, {})",
+                        width as f32 * side * 1.5 - hexagon_width / 2.0,
+                        dy - hexagon_height / 2.0
                     ),
-            );
+                ));
+            }
+
+            if y == 0 {
+                let dy = match x % 2 {
+                    1 => height as f32 * hexagon_height + hexagon_height / 2.0,
+                    _ => height as f32 * hexagon_height,
+                };
+
+                doc = doc.add(polyline.clone().set(
+                    "transform",
+                    format!(
+                        "translate({}, {})",
+                        (x as f32) * side * 1.5 - hexagon_width / 2.0,
+                        dy - hexagon_height / 2.0
+                    ),
+                ));
+            }
+
+            if x == 0 && y == 0 {
+                doc = doc.add(polyline.clone().set(
+                    "transform",
+                    format!(
+                        "translate({}, {})",
+                        width as f32 * side * 1.5 - hexagon_width / 2.0,
+                        5.0 * hexagon_height + hexagon_height / 2.0
+                    ),
+                ));
+            }
         }
     }
 
     doc
 }
 
-/// overlapping circles
+/// enum to identify the orientation of the hexagons in `hexagons_with`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexagonOrientation {
+    /// flat topped, in columns with every odd column shifted down by half a hexagon
+    Flat,
+    /// pointy topped, in rows with every odd row shifted right by half a hexagon
+    Pointy,
+}
+
+/// enum to identify how `hexagons_with` chooses the fill of each hexagon
+#[derive(Clone, Copy)]
+pub enum HexagonFill<'a> {
+    /// one fill for each hexagon, row by row
+    Grid(&'a [(&'a str, f32)]),
+    /// the fill for the cube coordinates `(q, r, s)` of each hexagon, where `q + r + s == 0`
+    ///
+    /// The hexagon in column `0` and row `0` is at the origin, and `q` grows along the columns
+    /// while `r` grows down the rows.
+    Cube(&'a dyn Fn(i32, i32, i32) -> (&'a str, f32)),
+    /// the fill for each ring around the hexagon at `(column, row)`, with the hexagons further out
+    /// than the last ring filled like the last ring
+    ///
+    /// The distances wrap around the edges, so the pattern still tiles.
+    Rings((usize, usize), &'a [(&'a str, f32)]),
+}
+
+/// hexagons, with a choice of orientation, spacing and fill
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/overlapping_circles.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/hexagons_with.svg)
+///
+/// `gap` is the space between the edges of neighbouring hexagons, and `corner_radius` rounds
+/// their corners.
+///
+/// The pattern tiles when the number of shifted columns (`Flat`) or rows (`Pointy`) is even.
 ///
 /// ```
-/// use geopattern::overlapping_circles;
+/// use geopattern::{hexagons_with, HexagonFill, HexagonOrientation};
 ///
-/// let c = overlapping_circles(
-///     60.0,
-///     (2, 2),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
+/// let c = hexagons_with(
+///     20.0,
+///     (4, 4),
+///     (HexagonOrientation::Pointy, 4.0, 3.0),
+///     &HexagonFill::Rings((1, 1), &[("#222", 0.8), ("#222", 0.4), ("#222", 0.1)]),
+///     ("#ddd", 0.2),
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn overlapping_circles(
-    radius: f32,
+pub fn hexagons_with(
+    side: f32,
     (width, height): (usize, usize),
-    fill: &[(&str, f32)],
+    (orientation, gap, corner_radius): (HexagonOrientation, f32, f32),
+    fill: &HexagonFill,
+    stroke: (&str, f32),
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(fill.len(), width * height);
+    match fill {
+        HexagonFill::Grid(fill) => debug_assert_eq!(fill.len(), width * height),
+        HexagonFill::Rings(_, rings) => debug_assert!(!rings.is_empty()),
+        HexagonFill::Cube(_) => {}
+    }
 
-    let mut doc = create_document(
-        (radius * width as f32, radius * height as f32),
-        background_color,
-    );
+    let root3 = 3.0_f32.sqrt();
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+    let (doc_width, doc_height) = match orientation {
+        HexagonOrientation::Flat => (side * 1.5 * width as f32, side * root3 * height as f32),
+        HexagonOrientation::Pointy => (side * root3 * width as f32, side * 1.5 * height as f32),
+    };
 
-            doc = doc.add(
-                Circle::new()
-                    .set("cx", x as f32 * radius)
-                    .set("cy", y as f32 * radius)
-                    .set("r", radius)
-                    .set("fill", fill[ix].0)
-                    .set("fill-opacity", fill[ix].1),
-            );
+    let center = |column: usize, row: usize| match orientation {
+        HexagonOrientation::Flat => (
+            side * 1.5 * column as f32,
+            side * root3 * (row as f32 + (column % 2) as f32 / 2.0),
+        ),
+        HexagonOrientation::Pointy => (
+            side * root3 * (column as f32 + (row % 2) as f32 / 2.0),
+            side * 1.5 * row as f32,
+        ),
+    };
 
-            if x == 0 {
-                doc = doc.add(
-                    Circle::new()
-                        .set("cx", width as f32 * radius)
-                        .set("cy", y as f32 * radius)
-                        .set("r", radius)
-                        .set("fill", fill[ix].0)
-                        .set("fill-opacity", fill[ix].1),
-                )
-            }
+    // cube coordinates of a cell in the offset layout, for any column and row, so the rings can
+    // be measured from the copies of their center around the edges
+    let cube = |column: i32, row: i32| {
+        let (q, r) = match orientation {
+            HexagonOrientation::Flat => (column, row - (column - (column & 1)) / 2),
+            HexagonOrientation::Pointy => (column - (row - (row & 1)) / 2, row),
+        };
 
-            if y == 0 {
-                doc = doc.add(
-                    Circle::new()
-                        .set("cx", x as f32 * radius)
-                        .set("cy", height as f32 * radius)
-                        .set("r", radius)
-                        .set("fill", fill[ix].0)
-                        .set("fill-opacity", fill[ix].1),
-                )
-            }
+        (q, r, -q - r)
+    };
 
-            if x == 0 && y == 0 {
-                doc = doc.add(
-                    Circle::new()
-                        .set("cx", width as f32 * radius)
-                        .set("cy", height as f32 * radius)
-                        .set("r", radius)
-                        .set("fill", fill[ix].0)
-                        .set("fill-opacity", fill[ix].1),
-                )
+    let radius = (side - gap / root3).max(0.0);
+    let corner_radius = corner_radius.min(radius * root3 / 2.0).max(0.0);
+
+    let offset = match orientation {
+        HexagonOrientation::Flat => 0.0,
+        HexagonOrientation::Pointy => std::f32::consts::FRAC_PI_6,
+    };
+
+    let vertices = (0..6)
+        .map(|i| {
+            let angle = offset + i as f32 * std::f32::consts::FRAC_PI_3;
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect::<Vec<(f32, f32)>>();
+
+    // the corners are cut where a circle of `corner_radius` touches both edges
+    let t = corner_radius / root3 / radius.max(f32::EPSILON);
+
+    let mut doc = create_document((doc_width, doc_height), background_color);
+
+    for row in 0..height {
+        for column in 0..width {
+            let (fill_color, fill_opacity) = match fill {
+                HexagonFill::Grid(fill) => fill[row * width + column],
+                HexagonFill::Cube(f) => {
+                    let (q, r, s) = cube(column as i32, row as i32);
+                    f(q, r, s)
+                }
+                HexagonFill::Rings((center_column, center_row), rings) => {
+                    let (q, r, _) = cube(column as i32, row as i32);
+
+                    let distance = (-1..=1)
+                        .flat_map(|i| (-1..=1).map(move |j| (i, j)))
+                        .map(|(i, j)| {
+                            let (cq, cr, _) = cube(
+                                *center_column as i32 + i * width as i32,
+                                *center_row as i32 + j * height as i32,
+                            );
+
+                            ((q - cq).abs() + (r - cr).abs() + (q - cq + r - cr).abs()) as usize / 2
+                        })
+                        .min()
+                        .unwrap_or(0);
+
+                    rings[distance.min(rings.len() - 1)]
+                }
+            };
+
+            let (cx, cy) = center(column, row);
+
+            for dx in &[-doc_width, 0.0, doc_width] {
+                for dy in &[-doc_height, 0.0, doc_height] {
+                    let (x, y) = (cx + dx, cy + dy);
+
+                    if x + side <= 0.0
+                        || x - side >= doc_width
+                        || y + side <= 0.0
+                        || y - side >= doc_height
+                    {
+                        continue;
+                    }
+
+                    let mut data = String::new();
+
+                    for i in 0..=6 {
+                        let (vx, vy) = vertices[i % 6];
+                        let (px, py) = vertices[(i + 5) % 6];
+                        let (nx, ny) = vertices[(i + 1) % 6];
+
+                        let from = (x + vx + (px - vx) * t, y + vy + (py - vy) * t);
+                        let to = (x + vx + (nx - vx) * t, y + vy + (ny - vy) * t);
+
+                        if i == 0 {
+                            data.push_str(&format!("M{},{}", to.0, to.1));
+                        } else if corner_radius > 0.0 {
+                            data.push_str(&format!(
+                                "L{},{}A{},{} 0 0 1 {},{}",
+                                from.0, from.1, corner_radius, corner_radius, to.0, to.1
+                            ));
+                        } else {
+                            data.push_str(&format!("L{},{}", x + vx, y + vy));
+                        }
+                    }
+
+                    doc = doc.add(
+                        Path::new()
+                            .set("d", format!("{}Z", data))
+                            .set("fill", fill_color)
+                            .set("fill-opacity", fill_opacity)
+                            .set("stroke", stroke.0)
+                            .set("stroke-opacity", stroke.1),
+                    );
+                }
             }
         }
     }
@@ -1269,294 +2225,543 @@ pub fn overlapping_circles(
     doc
 }
 
-/// overlapping rings
+/// Hitomezashi
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/overlapping_rings.svg)
+/// https://en.wikipedia.org/wiki/Sashiko
 ///
-/// TODO: consider having an `outer_radius`?
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/hitomezashi.svg)
+///
+/// Row `j` has stitches between `x = i` and `x = i + 1` for every `i` with `(i % 2 == 0) == rows[j]`,
+/// and column `i` has stitches between `y = j` and `y = j + 1` for every `j` with
+/// `(j % 2 == 0) == columns[i]`.
+///
+/// The stitches divide the grid into regions that can be coloured with two colours, `fill` is
+/// either empty to leave the regions unfilled, or has size 2.
+///
+/// The stitches tile seamlessly when `rows` and `columns` have even lengths. The fills also
+/// require an even number of `false` values in each of `rows` and `columns`, otherwise the two
+/// colours swap across the edges of the tile.
+///
+/// When `rows` or `columns` is empty, the pattern is only the background.
 ///
 /// ```
-/// use geopattern::overlapping_rings;
+/// use geopattern::hitomezashi;
 ///
-/// let c = overlapping_rings(
-///     60.0,
-///     (2, 2),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
+/// let c = hitomezashi(
+///     20.0,
+///     &[true, false, false, true],
+///     &[false, true, true, true, false, false],
+///     ("#222", 1.0, 2.0),
+///     &[("#ddd", 0.6), ("#987", 0.6)],
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
+///
+/// let empty = hitomezashi(20.0, &[], &[true, false], ("#222", 1.0, 2.0), &[], "#987987");
+///
+/// println!("{}", empty);
 /// ```
-pub fn overlapping_rings(
-    radius: f32,
-    (width, height): (usize, usize),
-    stroke: &[(&str, f32)],
+pub fn hitomezashi(
+    cell_size: f32,
+    rows: &[bool],
+    columns: &[bool],
+    (stroke_color, stroke_opacity, stroke_width): (&str, f32, f32),
+    fill: &[(&str, f32)],
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(stroke.len(), width * height);
+    debug_assert!(fill.is_empty() || fill.len() == 2);
+
+    let (width, height) = (columns.len(), rows.len());
 
     let mut doc = create_document(
-        (radius * width as f32, radius * height as f32),
+        (cell_size * width as f32, cell_size * height as f32),
         background_color,
     );
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+    if width == 0 || height == 0 {
+        return doc;
+    }
 
-            doc = doc.add(
-                Circle::new()
-                    .set("cx", x as f32 * radius)
-                    .set("cy", y as f32 * radius)
-                    .set("r", radius - radius / 8.0)
-                    .set("fill", "none")
-                    .set("stroke", stroke[ix].0)
-                    .set("stroke-opacity", stroke[ix].1)
-                    .set("stroke-width", radius / 4.0),
-            );
+    if !fill.is_empty() {
+        // crossing a stitch changes the colour of the region
+        let mut colors = vec![false; width * height];
 
-            if x == 0 {
-                doc = doc.add(
-                    Circle::new()
-                        .set("cx", width as f32 * radius)
-                        .set("cy", (y as f32) * radius)
-                        .set("r", radius - radius / 8.0)
-                        .set("fill", "none")
-                        .set("stroke", stroke[ix].0)
-                        .set("stroke-opacity", stroke[ix].1)
-                        .set("stroke-width", radius / 4.0),
-                )
+        for y in 0..height {
+            for x in 0..width {
+                colors[y * width + x] = match (x, y) {
+                    (0, 0) => false,
+                    (0, y) => colors[(y - 1) * width] ^ ((x % 2 == 0) == rows[y]),
+                    (x, y) => colors[y * width + x - 1] ^ ((y % 2 == 0) == columns[x]),
+                };
             }
+        }
 
-            if y == 0 {
-                doc = doc.add(
-                    Circle::new()
-                        .set("cx", (x as f32) * radius)
-                        .set("cy", height as f32 * radius)
-                        .set("r", radius - radius / 8.0)
-                        .set("fill", "none")
-                        .set("stroke", stroke[ix].0)
-                        .set("stroke-opacity", stroke[ix].1)
-                        .set("stroke-width", radius / 4.0),
-                )
-            }
+        for y in 0..height {
+            for x in 0..width {
+                let (color, opacity) = fill[colors[y * width + x] as usize];
 
-            if x == 0 && y == 0 {
                 doc = doc.add(
-                    Circle::new()
-                        .set("cx", width as f32 * radius)
-                        .set("cy", height as f32 * radius)
-                        .set("r", radius - radius / 8.0)
-                        .set("fill", "none")
-                        .set("stroke", stroke[ix].0)
-                        .set("stroke-opacity", stroke[ix].1)
-                        .set("stroke-width", radius / 4.0),
-                )
+                    Rectangle::new()
+                        .set("x", x as f32 * cell_size)
+                        .set("y", y as f32 * cell_size)
+                        .set("width", cell_size)
+                        .set("height", cell_size)
+                        .set("fill", color)
+                        .set("fill-opacity", opacity),
+                );
             }
         }
     }
 
-    doc
+    let mut path = String::new();
+
+    // the lines on the top and left edges are repeated on the bottom and right edges
+    for y in 0..=height {
+        for x in 0..width {
+            if (x % 2 == 0) == rows[y % height] {
+                path = format!(
+                    "{} M {} {} L {} {}",
+                    path,
+                    x as f32 * cell_size,
+                    y as f32 * cell_size,
+                    (x + 1) as f32 * cell_size,
+                    y as f32 * cell_size
+                );
+            }
+        }
+    }
+
+    for x in 0..=width {
+        for y in 0..height {
+            if (y % 2 == 0) == columns[x % width] {
+                path = format!(
+                    "{} M {} {} L {} {}",
+                    path,
+                    x as f32 * cell_size,
+                    y as f32 * cell_size,
+                    x as f32 * cell_size,
+                    (y + 1) as f32 * cell_size
+                );
+            }
+        }
+    }
+
+    doc.add(
+        Path::new()
+            .set("d", path.trim_start())
+            .set("fill", "none")
+            .set("stroke", stroke_color)
+            .set("stroke-opacity", stroke_opacity)
+            .set("stroke-width", stroke_width)
+            .set("stroke-linecap", "square"),
+    )
 }
 
-/// enum to identify the axis along which the splits need to be made
-pub enum PietMondrianSplitType {
-    /// X direction
-    X,
-    /// Y direction
-    Y,
+/// Houndstooth
+///
+/// https://en.wikipedia.org/wiki/Houndstooth
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/houndstooth.svg)
+///
+/// A 2/2 twill woven from bands of four threads in `fill` alternating with four threads in the
+/// background colour, in both the warp and the weft. Each thread is `unit` wide, so every tooth
+/// repeats after `8.0 * unit`.
+///
+/// ```
+/// use geopattern::houndstooth;
+///
+/// let c = houndstooth(5.0, (2, 2), ("#222", 0.8), "#987987");
+///
+/// println!("{}", c);
+/// ```
+pub fn houndstooth(
+    unit: f32,
+    (width, height): (usize, usize),
+    (fill_color, fill_opacity): (&str, f32),
+    background_color: &str,
+) -> Document {
+    let (columns, rows) = (8 * width, 8 * height);
+
+    let path = cell_path(unit, (columns, rows), |x, y| {
+        if (x + y) % 4 < 2 {
+            x % 8 < 4
+        } else {
+            y % 8 < 4
+        }
+    });
+
+    create_document(
+        (unit * columns as f32, unit * rows as f32),
+        background_color,
+    )
+    .add(
+        Path::new()
+            .set("d", path)
+            .set("fill", fill_color)
+            .set("fill-opacity", fill_opacity),
+    )
 }
 
-/// Piet Mondrian
+/// Hypnotic Squares
 ///
-/// https://generativeartistry.com/tutorials/piet-mondrian/
+/// https://generativeartistry.com/tutorials/hypnotic-squares/
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/piet_mondrian.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/hypnotic_squares.svg)
+///
+/// Each of `directions` is `(x, y)` with both in `-1..=1`, and values outside are clamped to it.
+/// See `HypnoticSquaresDirection`, and `hypnotic_squares_with` for other shapes.
 ///
 /// ```
-/// use geopattern::{piet_mondrian, PietMondrianSplitType};
+/// use geopattern::hypnotic_squares;
 ///
-/// let c = piet_mondrian(
-///     &vec![
-///         (PietMondrianSplitType::X, 80.0),
-///         (PietMondrianSplitType::Y, 220.0),
-///     ],
-///     (300.0, 300.0),
-///     &vec![("#FFF", 1.0), ("#00F", 1.0), ("#F00", 1.0), ("#FFF", 1.0)],
-///     ("#222", 8.0, 1.0),
-///     "#FFF",
+/// let c = hypnotic_squares(
+///     60.0,
+///     30.0,
+///     5,
+///     (2, 2),
+///     &(0..4)
+///         .map(|i| {
+///             (
+///                 (i % 3) as isize - 1,
+///                 (2 * i % 3) as isize - 1,
+///             )
+///         })
+///         .collect::<Vec<(isize, isize)>>(),
+///     &(0..4)
+///         .map(|v| ("#222", 1.0, 1.0))
+///         .collect::<Vec<(&str, f32, f32)>>(),
+///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn piet_mondrian(
-    splits: &[(PietMondrianSplitType, f32)],
-    (width, height): (f32, f32),
-    fill: &[(&str, f32)],
-    stroke: (&str, f32, f32),
+pub fn hypnotic_squares(
+    side: f32,
+    min_side: f32,
+    steps: usize,
+    (width, height): (usize, usize),
+    directions: &[(isize, isize)],
+    stroke: &[(&str, f32, f32)],
     background_color: &str,
 ) -> Document {
-    let mut doc = create_document((width as f32, height as f32), background_color);
+    hypnotic_squares_with(
+        side,
+        min_side,
+        steps,
+        (width, height),
+        &directions
+            .iter()
+            .map(|(x, y)| {
+                HypnoticSquaresDirection::try_from((x.signum(), y.signum()))
+                    .unwrap_or(HypnoticSquaresDirection::Center)
+            })
+            .collect::<Vec<HypnoticSquaresDirection>>(),
+        HypnoticSquaresShape::Square,
+        &stroke
+            .iter()
+            .map(|(color, stroke_width, stroke_opacity)| {
+                ((*color, *color), *stroke_width, *stroke_opacity)
+            })
+            .collect::<Vec<((&str, &str), f32, f32)>>(),
+        background_color,
+    )
+}
 
-    let mut squares = vec![(0.0, 0.0, width, height)];
-    let mut new_squares;
+/// enum to identify the direction the shapes in `hypnotic_squares_with` move in as they get
+/// smaller
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HypnoticSquaresDirection {
+    /// towards the top left corner
+    UpLeft,
+    /// towards the top edge
+    Up,
+    /// towards the top right corner
+    UpRight,
+    /// towards the left edge
+    Left,
+    /// staying in the center
+    Center,
+    /// towards the right edge
+    Right,
+    /// towards the bottom left corner
+    DownLeft,
+    /// towards the bottom edge
+    Down,
+    /// towards the bottom right corner
+    DownRight,
+}
 
-    for split in splits {
-        new_squares = vec![];
-
-        for s in squares {
-            let (split_type, split_location) = split;
-            match split_type {
-                PietMondrianSplitType::X => {
-                    let (x, y, w, h) = s;
-                    if x < *split_location && x + w > *split_location {
-                        new_squares.push((x, y, w - (x + w - split_location), h));
-                        new_squares.push((*split_location, y, x + w - split_location, h));
-                    } else {
-                        new_squares.push(s);
-                    }
-                }
-                PietMondrianSplitType::Y => {
-                    let (x, y, w, h) = s;
-                    if y < *split_location && y + h > *split_location {
-                        new_squares.push((x, y, w, h - (y + h - split_location)));
-                        new_squares.push((x, *split_location, w, y + h - split_location));
-                    } else {
-                        new_squares.push(s);
-                    }
-                }
-            }
+impl HypnoticSquaresDirection {
+    /// the `(x, y)` direction, each in `-1..=1`
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            HypnoticSquaresDirection::UpLeft => (-1, -1),
+            HypnoticSquaresDirection::Up => (0, -1),
+            HypnoticSquaresDirection::UpRight => (1, -1),
+            HypnoticSquaresDirection::Left => (-1, 0),
+            HypnoticSquaresDirection::Center => (0, 0),
+            HypnoticSquaresDirection::Right => (1, 0),
+            HypnoticSquaresDirection::DownLeft => (-1, 1),
+            HypnoticSquaresDirection::Down => (0, 1),
+            HypnoticSquaresDirection::DownRight => (1, 1),
         }
-
-        squares = new_squares;
     }
+}
 
-    debug_assert_eq!(squares.len(), fill.len());
-
-    for (i, square) in squares.into_iter().enumerate() {
-        let (x, y, w, h) = square;
-        doc = doc.add(
-            Rectangle::new()
-                .set("x", x)
-                .set("y", y)
-                .set("width", w)
-                .set("height", h)
-                .set("fill", fill[i].0)
-                .set("fill-opacity", fill[i].1)
-                .set("stroke", stroke.0)
-                .set("stroke-width", stroke.1)
-                .set("stroke-opacity", stroke.2),
-        );
+impl TryFrom<(isize, isize)> for HypnoticSquaresDirection {
+    type Error = (isize, isize);
+
+    /// the direction for `(x, y)`, or the pair back when either is outside `-1..=1`
+    fn try_from(offset: (isize, isize)) -> Result<Self, Self::Error> {
+        match offset {
+            (-1, -1) => Ok(HypnoticSquaresDirection::UpLeft),
+            (0, -1) => Ok(HypnoticSquaresDirection::Up),
+            (1, -1) => Ok(HypnoticSquaresDirection::UpRight),
+            (-1, 0) => Ok(HypnoticSquaresDirection::Left),
+            (0, 0) => Ok(HypnoticSquaresDirection::Center),
+            (1, 0) => Ok(HypnoticSquaresDirection::Right),
+            (-1, 1) => Ok(HypnoticSquaresDirection::DownLeft),
+            (0, 1) => Ok(HypnoticSquaresDirection::Down),
+            (1, 1) => Ok(HypnoticSquaresDirection::DownRight),
+            _ => Err(offset),
+        }
     }
+}
 
-    doc
+/// enum to identify the shape nested in each cell of `hypnotic_squares_with`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HypnoticSquaresShape {
+    /// squares, as in `hypnotic_squares`
+    Square,
+    /// circles
+    Circle,
+    /// flat topped hexagons
+    Hexagon,
+    /// triangles pointing up
+    Triangle,
 }
 
-/// plaid
+/// Hypnotic Squares, with a choice of shape and colours fading inwards
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/plaid.svg)
+/// https://generativeartistry.com/tutorials/hypnotic-squares/
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/hypnotic_squares_with.svg)
+///
+/// Each of `stroke` is `((outer_color, inner_color), stroke_width, stroke_opacity)`, and the
+/// shapes in a cell go from `outer_color` to `inner_color` in `steps`. Colours are interpolated
+/// when both are in `#rgb`, `#rrggbb` or `rgb(r, g, b)` form, otherwise every shape is
+/// `outer_color`.
+///
+/// The sizes of the shapes are the sizes of their bounding circles for `Circle`, `Hexagon` and
+/// `Triangle`.
 ///
 /// ```
-/// use geopattern::plaid;
+/// use geopattern::{hypnotic_squares_with, HypnoticSquaresDirection, HypnoticSquaresShape};
 ///
-/// let c = plaid(
-///     &(0..4).map(|v| 5.0 + v as f32 * 4.0).collect::<Vec<f32>>(),
-///     &(0..4).map(|v| 3.0 + v as f32 * 7.0).collect::<Vec<f32>>(),
+/// let c = hypnotic_squares_with(
+///     60.0,
+///     30.0,
+///     5,
+///     (2, 2),
+///     &[
+///         HypnoticSquaresDirection::UpLeft,
+///         HypnoticSquaresDirection::Center,
+///         HypnoticSquaresDirection::Down,
+///         HypnoticSquaresDirection::Right,
+///     ],
+///     HypnoticSquaresShape::Hexagon,
 ///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
+///         .map(|_| (("#222", "#ddd"), 1.0, 1.0))
+///         .collect::<Vec<((&str, &str), f32, f32)>>(),
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn plaid(
-    distances: &[f32],
-    sizes: &[f32],
-    fill: &[(&str, f32)],
+#[allow(clippy::too_many_arguments)]
+pub fn hypnotic_squares_with(
+    side: f32,
+    min_side: f32,
+    steps: usize,
+    (width, height): (usize, usize),
+    directions: &[HypnoticSquaresDirection],
+    shape: HypnoticSquaresShape,
+    stroke: &[((&str, &str), f32, f32)],
     background_color: &str,
 ) -> Document {
-    let n = distances.len();
+    debug_assert!(min_side < side);
+    debug_assert_eq!(stroke.len(), width * height);
+    debug_assert_eq!(directions.len(), width * height);
 
-    debug_assert_eq!(sizes.len(), n);
-    debug_assert_eq!(fill.len(), n);
+    let mut doc = create_document(
+        (side * width as f32, side * height as f32),
+        background_color,
+    );
 
-    let (mut w, mut h) = (0.0, 0.0);
+    let step_size = (side - min_side) / steps as f32;
 
-    let mut doc = create_document((0, 0), background_color);
+    let create_group = |(outer, inner): (&str, &str), stroke_width, stroke_opacity, xdir, ydir| {
+        let mut g = Group::new();
 
-    for i in 0..n {
-        h += distances[i];
+        for i in 0..steps {
+            let stroke = if outer == inner {
+                outer.to_string()
+            } else {
+                interpolate_color(
+                    (outer, inner),
+                    i as f32 / steps.saturating_sub(1).max(1) as f32,
+                )
+            };
 
-        doc = doc.add(
-            Rectangle::new()
-                .set("x", 0)
-                .set("y", h)
-                .set("width", "100%")
-                .set("height", sizes[i])
-                .set("opacity", fill[i].1)
-                .set("fill", fill[i].0),
-        );
+            let (x, y) = (
+                step_size * i as f32 + step_size / 2.0 * i as f32 * xdir as f32,
+                step_size * i as f32 + step_size / 2.0 * i as f32 * ydir as f32,
+            );
+            let size = side - 2.0 * step_size * i as f32;
 
-        h += sizes[i];
+            let (cx, cy, r) = (x + size / 2.0, y + size / 2.0, size / 2.0);
+
+            g = match shape {
+                HypnoticSquaresShape::Square => g.add(
+                    Rectangle::new()
+                        .set("x", x)
+                        .set("y", y)
+                        .set("width", size)
+                        .set("height", size)
+                        .set("fill", "none")
+                        .set("stroke", stroke)
+                        .set("stroke-width", stroke_width)
+                        .set("stroke-opacity", stroke_opacity),
+                ),
+                HypnoticSquaresShape::Circle => g.add(
+                    Circle::new()
+                        .set("cx", cx)
+                        .set("cy", cy)
+                        .set("r", r)
+                        .set("fill", "none")
+                        .set("stroke", stroke)
+                        .set("stroke-width", stroke_width)
+                        .set("stroke-opacity", stroke_opacity),
+                ),
+                HypnoticSquaresShape::Hexagon | HypnoticSquaresShape::Triangle => {
+                    let (sides, rotation) = match shape {
+                        HypnoticSquaresShape::Hexagon => (6, 0.0),
+                        _ => (3, -std::f32::consts::FRAC_PI_2),
+                    };
+
+                    let points = (0..sides)
+                        .map(|k| {
+                            let a = rotation + 2.0 * std::f32::consts::PI * k as f32 / sides as f32;
+                            (cx + r * a.cos(), cy + r * a.sin())
+                        })
+                        .collect::<Vec<(f32, f32)>>();
+
+                    g.add(
+                        Polyline::new()
+                            .set("points", polygon_points(&points))
+                            .set("fill", "none")
+                            .set("stroke", stroke)
+                            .set("stroke-width", stroke_width)
+                            .set("stroke-opacity", stroke_opacity),
+                    )
+                }
+            };
+        }
+
+        g
+    };
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+            let (xdir, ydir) = directions[ix].offset();
+
+            doc = doc.add(
+                create_group(stroke[ix].0, stroke[ix].1, stroke[ix].2, xdir, ydir).set(
+                    "transform",
+                    format!("translate({} {})", x as f32 * side, y as f32 * side),
+                ),
+            )
+        }
     }
 
-    for i in 0..n {
-        w += distances[i];
+    doc
+}
 
-        doc = doc.add(
-            Rectangle::new()
-                .set("x", w)
-                .set("y", 0)
-                .set("width", sizes[i])
-                .set("height", "100%")
-                .set("opacity", fill[i].1)
-                .set("fill", fill[i].0),
-        );
+/// `#rgb`, `#rrggbb` or `rgb(r, g, b)` as `(r, g, b)`
+fn parse_color(color: &str) -> Option<(u8, u8, u8)> {
+    let color = color.trim();
+
+    if let Some(hex) = color.strip_prefix('#') {
+        let digits = hex
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8))
+            .collect::<Option<Vec<u8>>>()?;
+
+        return match digits.len() {
+            3 => Some((digits[0] * 17, digits[1] * 17, digits[2] * 17)),
+            6 => Some((
+                digits[0] * 16 + digits[1],
+                digits[2] * 16 + digits[3],
+                digits[4] * 16 + digits[5],
+            )),
+            _ => None,
+        };
+    }
 
-        w += sizes[i];
+    let channels = color
+        .strip_prefix("rgb(")?
+        .strip_suffix(')')?
+        .split(',')
+        .map(|c| c.trim().parse::<u8>().ok())
+        .collect::<Option<Vec<u8>>>()?;
+
+    match channels.len() {
+        3 => Some((channels[0], channels[1], channels[2])),
+        _ => None,
     }
+}
 
-    doc.set("width", w).set("height", h)
+/// the colour `t` of the way from `from` to `to`, or `from` when either cannot be parsed
+fn interpolate_color((from, to): (&str, &str), t: f32) -> String {
+    match (parse_color(from), parse_color(to)) {
+        (Some(a), Some(b)) => {
+            let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+            format!("rgb({},{},{})", mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+        }
+        _ => from.to_string(),
+    }
 }
 
-fn plus(side: f32) -> (Rectangle, Rectangle) {
-    (
-        Rectangle::new()
-            .set("x", side)
-            .set("y", 0)
-            .set("width", side)
-            .set("height", side * 3.0),
-        Rectangle::new()
-            .set("x", 0)
-            .set("y", side)
-            .set("width", side * 3.0)
-            .set("height", side),
-    )
+/// enum to identify the arrangement of cubes in `isometric_cubes`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IsometricCubesVariant {
+    /// cubes on a hexagonal grid, all viewed from above
+    Cubes,
+    /// cubes on a hexagonal grid, every other cube viewed from below
+    TumblingBlocks,
+    /// columns of cubes, each cube in front of and below the previous one
+    Stairs,
 }
 
-/// plus_signs
+/// Isometric Cubes
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/plus_signs.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/isometric_cubes.svg)
+///
+/// Each cube is drawn with three rhombi in the same fill, the side faces darkened by an overlay.
+/// Cells that are `false` in `cubes` are left out.
+///
+/// `Cubes` requires an even `height`, `TumblingBlocks` requires an even `width` and `height`,
+/// and `Stairs` requires an even `width`.
 ///
 /// ```
-/// use geopattern::plus_signs;
+/// use geopattern::{isometric_cubes, IsometricCubesVariant};
 ///
-/// let c = plus_signs(
-///     60.0,
+/// let c = isometric_cubes(
+///     20.0,
 ///     (2, 2),
+///     IsometricCubesVariant::Cubes,
 ///     &(0..4)
 ///         .map(|v| {
 ///             (
@@ -1565,252 +2770,2429 @@ fn plus(side: f32) -> (Rectangle, Rectangle) {
 ///             )
 ///         })
 ///         .collect::<Vec<(&str, f32)>>(),
+///     &(0..4).map(|v| v != 2).collect::<Vec<bool>>(),
 ///     ("#ddd", 0.2),
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn plus_signs(
+pub fn isometric_cubes(
     side: f32,
     (width, height): (usize, usize),
+    variant: IsometricCubesVariant,
     fill: &[(&str, f32)],
+    cubes: &[bool],
     stroke: (&str, f32),
     background_color: &str,
 ) -> Document {
     debug_assert_eq!(fill.len(), width * height);
+    debug_assert_eq!(cubes.len(), width * height);
 
-    let mut doc = create_document(
-        (side * 2.0 * width as f32, side * 2.0 * height as f32),
-        background_color,
-    );
+    let cube_width = side * 3.0_f32.sqrt();
 
-    let length = side * 3.0;
+    let (tile_width, tile_height) = match variant {
+        IsometricCubesVariant::Cubes => {
+            debug_assert_eq!(height % 2, 0);
+            (cube_width * width as f32, side * 1.5 * height as f32)
+        }
+        IsometricCubesVariant::TumblingBlocks => {
+            debug_assert_eq!(width % 2, 0);
+            debug_assert_eq!(height % 2, 0);
+            (cube_width * width as f32, side * 1.5 * height as f32)
+        }
+        IsometricCubesVariant::Stairs => {
+            debug_assert_eq!(width % 2, 0);
+            (cube_width * width as f32, side * height as f32)
+        }
+    };
 
-    let rects = plus(side);
+    let mut doc = create_document((tile_width, tile_height), background_color);
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+    if width == 0 || height == 0 {
+        return doc;
+    }
 
-            let dx = (y % 2) as f32;
-
-            let g = Group::new()
-                .set("fill", fill[ix].0)
-                .set("stroke", stroke.0)
-                .set("stroke-opacity", stroke.1)
-                .set("fill-opacity", fill[ix].1)
-                .add(rects.0.clone())
-                .add(rects.1.clone());
+    let (a, b) = (cube_width / 2.0, side / 2.0);
+
+    // faces around the center, from the lightest to the darkest
+    let upright = [
+        format!("0,0,{},{},0,{},{},{},0,0", -a, -b, -side, a, -b),
+        format!("0,0,{},{},{},{},0,{},0,0", -a, -b, -a, b, side),
+        format!("0,0,{},{},{},{},0,{},0,0", a, -b, a, b, side),
+    ];
+    let inverted = [
+        format!("0,0,{},{},0,{},{},{},0,0", -a, b, side, a, b),
+        format!("0,0,{},{},{},{},0,{},0,0", -a, b, -a, -b, -side),
+        format!("0,0,{},{},{},{},0,{},0,0", a, b, a, -b, -side),
+    ];
+    let shades = [0.0, 0.2, 0.4];
+
+    let cube = |faces: &[String; 3], ix: usize| {
+        let mut g = Group::new()
+            .set("fill", fill[ix].0)
+            .set("fill-opacity", fill[ix].1)
+            .set("stroke", stroke.0)
+            .set("stroke-opacity", stroke.1);
 
-            doc = doc.add(g.clone().set(
-                "transform",
-                format!(
-                    "translate({}, {})",
-                    (x as f32) * (length - side) + dx * side - side,
-                    (y as f32) * (length - side) - length / 2.0
-                ),
-            ));
+        for (face, shade) in faces.iter().zip(shades.iter()) {
+            g = g.add(Polyline::new().set("points", face.as_str()));
 
-            if x == 0 {
-                doc = doc.add(g.clone().set(
-                    "transform",
-                    format!(
-                        "translate({}, {})",
-                        4.0 * length - (x as f32) * side + dx * side - side,
-                        (y as f32) * (length - side) - length / 2.0
-                    ),
-                ));
+            if *shade > 0.0 {
+                g = g.add(
+                    Polyline::new()
+                        .set("points", face.as_str())
+                        .set("fill", "#000")
+                        .set("fill-opacity", *shade),
+                );
             }
+        }
 
-            if y == 0 {
-                doc = doc.add(g.clone().set(
-                    "transform",
-                    format!(
-                        "translate({}, {})",
-                        (x as f32) * (length - side) + dx * side - side,
-                        4.0 * length - (y as f32) * side - length / 2.0
-                    ),
-                ));
-            }
+        g
+    };
 
-            if x == 0 && y == 0 {
-                doc = doc.add(g.clone().set(
-                    "transform",
-                    format!(
-                        "translate({}, {})",
-                        4.0 * length - (x as f32) * side + dx * side - side,
-                        4.0 * length - (y as f32) * side - length / 2.0
-                    ),
-                ));
+    // one extra row and column on each side, for the cubes that wrap around the edges
+    for y in -1..=height as isize {
+        for x in -1..=width as isize {
+            let (xx, yy) = (
+                x.rem_euclid(width as isize) as usize,
+                y.rem_euclid(height as isize) as usize,
+            );
+            let ix = yy * width + xx;
+
+            if !cubes[ix] {
+                continue;
             }
+
+            let (faces, cx, cy) = match variant {
+                IsometricCubesVariant::Cubes | IsometricCubesVariant::TumblingBlocks => {
+                    let dx = match yy % 2 {
+                        1 => a,
+                        _ => 0.0,
+                    };
+
+                    let faces = match variant {
+                        IsometricCubesVariant::TumblingBlocks if (xx + yy) % 2 == 1 => &inverted,
+                        _ => &upright,
+                    };
+
+                    (faces, x as f32 * cube_width + dx, y as f32 * side * 1.5)
+                }
+                IsometricCubesVariant::Stairs => {
+                    let dy = match xx % 2 {
+                        1 => b,
+                        _ => 0.0,
+                    };
+
+                    (&upright, x as f32 * cube_width + a, y as f32 * side + dy)
+                }
+            };
+
+            doc = doc.add(cube(faces, ix).set("transform", format!("translate({}, {})", cx, cy)));
         }
     }
 
     doc
 }
 
-/// sine waves
+/// Joy Division
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/sine_waves.svg)
+/// https://generativeartistry.com/tutorials/joy-division/
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/joy_division.svg)
+///
+/// Each line is filled with the background, so it hides the lines above it. Negative heights
+/// raise the line. See `joy_division_classic` for heights generated from a seed.
 ///
 /// ```
-/// use geopattern::sine_waves;
+/// use geopattern::joy_division;
 ///
-/// let c = sine_waves(
-///     120.0,
-///     80.0,
-///     20.0,
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
+/// let c = joy_division(
+///     60.0,
+///     (2, 2),
+///     &(0..4).map(|v| v as f32).collect::<Vec<f32>>(),
+///     ("#333", 0.5, 2.0),
+///     2,
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn sine_waves(
-    period: f32,
-    a: f32,
-    ww: f32,
-    stroke: &[(&str, f32)],
+pub fn joy_division(
+    step_size: f32,
+    (width, height): (usize, usize),
+    pulse_heights: &[f32],
+    (stroke_color, stroke_opacity, stroke_width): (&str, f32, f32),
+    padding_top: usize,
     background_color: &str,
 ) -> Document {
-    let n = stroke.len();
+    debug_assert_eq!(pulse_heights.len(), width * height);
 
-    let mut doc = create_document((period, ww * n as f32), background_color);
+    let mut doc = create_document(
+        (
+            width as f32 * step_size,
+            (height + 1 + padding_top) as f32 * step_size,
+        ),
+        background_color,
+    );
 
-    for (i, s) in stroke.iter().enumerate() {
-        let xoff = (period / 4.0) * 0.7;
+    for y in 0..height {
+        let top = (y + 1 + padding_top) as f32 * step_size + stroke_width / 2.0;
+        let mut path = format!("M {} {}", 0, top);
 
-        let path = Path::new()
-            .set(
-                "d",
-                format!(
-                    "M0 {} C {} 0, {} 0, {} {} S {} {}, {} {} S {} 0, {}, {}",
-                    a,
-                    xoff,
-                    period / 2.0 - xoff,
-                    period / 2.0,
-                    a,
-                    period - xoff,
-                    a * 2.0,
-                    period,
-                    a,
-                    period * 1.5 - xoff,
-                    period * 1.5,
-                    a
-                ),
-            )
-            .set("fill", "none")
-            .set("stroke", s.0)
-            .set("stroke-opacity", s.1)
-            .set("stroke-width", ww);
+        let (mut pleft, mut ptop) = (0.0, top);
 
-        doc = doc.add(path.clone().set(
-            "transform",
-            format!(
-                "translate(-{}, {})",
-                period / 4.0,
-                (ww * i as f32) - (a * 1.5)
-            ),
-        ));
+        for x in 0..width {
+            let ix = y * width + x;
 
-        doc = doc.add(path.clone().set(
-            "transform",
-            format!(
-                "translate(-{}, {})",
-                period / 4.0,
-                (ww * i as f32) - (a * 1.5) + ww * n as f32
-            ),
-        ));
+            let top = top + pulse_heights[ix];
+            let left = (x + 1) as f32 * step_size;
+
+            path = format!(
+                "{} Q {} {} {} {}",
+                path,
+                pleft,
+                ptop,
+                (left + pleft) / 2.0,
+                (top + ptop) / 2.0,
+            );
+
+            ptop = top;
+            pleft = left;
+        }
+
+        path = format!(
+            "{} Q {} {} {} {}",
+            path,
+            pleft,
+            ptop,
+            width as f32 * step_size,
+            top
+        );
+
+        doc = doc.add(
+            Path::new()
+                .set("d", path)
+                .set("fill", background_color)
+                .set("stroke", stroke_color)
+                .set("stroke-opacity", stroke_opacity)
+                .set("stroke-width", stroke_width),
+        );
     }
 
     doc
 }
 
-/// squares
+/// Joy Division, with the profile of the Unknown Pleasures cover
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/squares.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/joy_division_classic.svg)
+///
+/// Draws `lines` lines of `width` steps with `joy_division`, generating the pulse heights from
+/// `seed`. The pulses are up to `amplitude` high, and rise out of a flat line in a band that
+/// spans `center_width` of the width around the center, `0.0` being flat and `1.0` the whole
+/// width.
 ///
 /// ```
-/// use geopattern::squares;
+/// use geopattern::joy_division_classic;
 ///
-/// let c = squares(
-///     60.0,
-///     (2, 2),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     ("#ddd", 0.2),
-///     "#987987",
+/// let c = joy_division_classic(
+///     8.0,
+///     (30, 20),
+///     7,
+///     (0.5, 40.0),
+///     ("#ddd", 1.0, 1.5),
+///     4,
+///     "#222",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn squares(
-    side: f32,
-    (width, height): (usize, usize),
-    fill: &[(&str, f32)],
-    stroke: (&str, f32),
+pub fn joy_division_classic(
+    step_size: f32,
+    (width, lines): (usize, usize),
+    seed: u64,
+    (center_width, amplitude): (f32, f32),
+    stroke: (&str, f32, f32),
+    padding_top: usize,
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(fill.len(), width * height);
+    let noise = Noise {
+        octaves: 3,
+        ..Noise::new(NoiseKind::Value, seed, 0.35)
+    };
+    let mut rng = Rng::new(seed);
 
-    let mut doc = create_document(
-        (side * width as f32, side * height as f32),
-        background_color,
-    );
+    let mut pulse_heights = Vec::with_capacity(width * lines);
 
-    for y in 0..height {
+    for y in 0..lines {
         for x in 0..width {
-            let ix = y * width + x;
+            // distance from the center, in fractions of the band
+            let d = ((x + 1) as f32 / width as f32 - 0.5).abs() * 2.0 / center_width;
+            let envelope = if d < 1.0 {
+                (d * std::f32::consts::FRAC_PI_2).cos().powi(2)
+            } else {
+                0.0
+            };
 
-            doc = doc.add(
-                Rectangle::new()
-                    .set("x", (x as f32) * side)
-                    .set("y", (y as f32) * side)
-                    .set("width", side)
-                    .set("height", side)
-                    .set("fill", fill[ix].0)
-                    .set("fill-opacity", fill[ix].1)
-                    .set("stroke", stroke.0)
-                    .set("stroke-opacity", stroke.1),
-            );
+            // a few tall peaks out of the noise, over a little jitter along the whole line
+            let peak = ((noise.get(x as f32, y as f32 * 7.0) + 1.0) / 2.0).powi(3);
+            let jitter = rng.next_f32() * 0.02;
+
+            pulse_heights.push(-amplitude * (envelope * (peak + jitter) + jitter));
         }
     }
 
-    doc
+    joy_division(
+        step_size,
+        (width, lines),
+        &pulse_heights,
+        stroke,
+        padding_top,
+        background_color,
+    )
 }
 
-/// tesselation
+/// L-System
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/tesselation.svg)
+/// https://en.wikipedia.org/wiki/L-system
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/lsystem.svg)
+///
+/// The lines drawn by the turtle after `iterations` are scaled to fit `(width, height)`,
+/// see [`LSystem`](lsystem/struct.LSystem.html) for the symbols it understands.
 ///
 /// ```
-/// use geopattern::tesselation;
+/// use geopattern::{lsystem, lsystem::LSystem};
 ///
-/// let c = tesselation(
-///     60.0,
-///     &(0..20)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     ("#ddd", 0.2),
+/// let c = lsystem(
+///     &LSystem::koch_snowflake(),
+///     3,
+///     (200.0, 200.0),
+///     ("#222", 1.0, 2.0),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn lsystem(
+    system: &LSystem,
+    iterations: usize,
+    (width, height): (f32, f32),
+    (stroke_color, stroke_opacity, stroke_width): (&str, f32, f32),
+    background_color: &str,
+) -> Document {
+    create_document((width, height), background_color).add(
+        Path::new()
+            .set("d", system.path_data(iterations, (width, height)))
+            .set("fill", "none")
+            .set("stroke", stroke_color)
+            .set("stroke-opacity", stroke_opacity)
+            .set("stroke-width", stroke_width)
+            .set("stroke-linecap", "round")
+            .set("stroke-linejoin", "round"),
+    )
+}
+
+/// enum to identify the algorithm used to generate a `maze`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MazeAlgorithm {
+    /// randomized depth first search, long winding passages
+    RecursiveBacktracker,
+    /// randomized Kruskal's algorithm, many short dead ends
+    Kruskal,
+    /// loop erased random walks, an unbiased sample of all mazes
+    Wilson,
+}
+
+/// enum to identify what is drawn by `maze`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MazeStyle {
+    /// the walls between cells
+    Walls,
+    /// the passages between cell centers
+    Passages,
+}
+
+/// the open passages of a perfect maze, `(right, down)` for every cell
+fn carve_maze(
+    (width, height): (usize, usize),
+    seed: u64,
+    algorithm: MazeAlgorithm,
+    toroidal: bool,
+) -> (Vec<bool>, Vec<bool>) {
+    let n = width * height;
+    let mut rng = Rng::new(seed);
+
+    // edges are identified as `2 * ix` for the right and `2 * ix + 1` for the bottom of a cell
+    let neighbours = |ix: usize| {
+        let (x, y) = (ix % width, ix / width);
+        let mut v = Vec::with_capacity(4);
+
+        if x + 1 < width || toroidal {
+            v.push((y * width + (x + 1) % width, 2 * ix));
+        }
+        if x > 0 || toroidal {
+            let left = y * width + (x + width - 1) % width;
+            v.push((left, 2 * left));
+        }
+        if y + 1 < height || toroidal {
+            v.push((((y + 1) % height) * width + x, 2 * ix + 1));
+        }
+        if y > 0 || toroidal {
+            let up = ((y + height - 1) % height) * width + x;
+            v.push((up, 2 * up + 1));
+        }
+
+        v.retain(|(c, _)| *c != ix);
+        v
+    };
+
+    let mut open = vec![false; 2 * n];
+
+    match algorithm {
+        MazeAlgorithm::RecursiveBacktracker => {
+            let mut visited = vec![false; n];
+            let start = rng.below(n);
+            let mut stack = vec![start];
+            visited[start] = true;
+
+            while let Some(&current) = stack.last() {
+                let candidates = neighbours(current)
+                    .into_iter()
+                    .filter(|(c, _)| !visited[*c])
+                    .collect::<Vec<(usize, usize)>>();
+
+                if candidates.is_empty() {
+                    stack.pop();
+                    continue;
+                }
+
+                let (next, edge) = candidates[rng.below(candidates.len())];
+                open[edge] = true;
+                visited[next] = true;
+                stack.push(next);
+            }
+        }
+        MazeAlgorithm::Kruskal => {
+            let mut edges = (0..n)
+                .flat_map(|ix| neighbours(ix).into_iter().map(move |(c, e)| (ix, c, e)))
+                .filter(|(ix, _, e)| e / 2 == *ix)
+                .collect::<Vec<(usize, usize, usize)>>();
+            rng.shuffle(&mut edges);
+
+            let mut parent = (0..n).collect::<Vec<usize>>();
+            fn find(parent: &mut [usize], mut i: usize) -> usize {
+                while parent[i] != i {
+                    parent[i] = parent[parent[i]];
+                    i = parent[i];
+                }
+                i
+            }
+
+            for (a, b, edge) in edges {
+                let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+                if ra != rb {
+                    parent[ra] = rb;
+                    open[edge] = true;
+                }
+            }
+        }
+        MazeAlgorithm::Wilson => {
+            let mut in_maze = vec![false; n];
+            in_maze[rng.below(n)] = true;
+
+            // the last step taken out of every cell, later steps erase the loops
+            let mut step = vec![(0, 0); n];
+
+            for start in 0..n {
+                let mut current = start;
+                while !in_maze[current] {
+                    let candidates = neighbours(current);
+                    step[current] = candidates[rng.below(candidates.len())];
+                    current = step[current].0;
+                }
+
+                let mut current = start;
+                while !in_maze[current] {
+                    in_maze[current] = true;
+                    open[step[current].1] = true;
+                    current = step[current].0;
+                }
+            }
+        }
+    }
+
+    (
+        open.iter().step_by(2).cloned().collect(),
+        open.iter().skip(1).step_by(2).cloned().collect(),
+    )
+}
+
+/// maze
+///
+/// https://en.wikipedia.org/wiki/Maze_generation_algorithm
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/maze.svg)
+///
+/// A perfect maze on a `(width, height)` grid, generated from `seed`. A `toroidal` maze has
+/// passages that wrap around the edges, so that it tiles seamlessly.
+///
+/// `stroke` is `(color, opacity, width, linecap)`.
+///
+/// ```
+/// use geopattern::{maze, MazeAlgorithm, MazeStyle};
+///
+/// let c = maze(
+///     20.0,
+///     (4, 4),
+///     42,
+///     MazeAlgorithm::RecursiveBacktracker,
+///     true,
+///     MazeStyle::Walls,
+///     ("#222", 0.8, 4.0, "round"),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn maze(
+    cell_size: f32,
+    (width, height): (usize, usize),
+    seed: u64,
+    algorithm: MazeAlgorithm,
+    toroidal: bool,
+    style: MazeStyle,
+    (stroke_color, stroke_opacity, stroke_width, stroke_linecap): (&str, f32, f32, &str),
+    background_color: &str,
+) -> Document {
+    if width == 0 || height == 0 {
+        return create_document(
+            (cell_size * width as f32, cell_size * height as f32),
+            background_color,
+        );
+    }
+
+    let (right, down) = carve_maze((width, height), seed, algorithm, toroidal);
+
+    let mut doc = create_document(
+        (cell_size * width as f32, cell_size * height as f32),
+        background_color,
+    );
+
+    let mut path = String::new();
+    let mut line = |(x1, y1): (f32, f32), (x2, y2): (f32, f32)| {
+        path = format!(
+            "{} M {} {} L {} {}",
+            path,
+            x1 * cell_size,
+            y1 * cell_size,
+            x2 * cell_size,
+            y2 * cell_size
+        );
+    };
+
+    match style {
+        MazeStyle::Walls => {
+            for y in 0..height {
+                for x in 0..width {
+                    let ix = y * width + x;
+                    let (x, y) = (x as f32, y as f32);
+
+                    if !right[ix] {
+                        line((x + 1.0, y), (x + 1.0, y + 1.0));
+                    }
+                    if !down[ix] {
+                        line((x, y + 1.0), (x + 1.0, y + 1.0));
+                    }
+                }
+            }
+
+            // the left and top edges, repeated from the right and bottom for a toroidal maze
+            for y in 0..height {
+                if !toroidal || !right[y * width + width - 1] {
+                    line((0.0, y as f32), (0.0, (y + 1) as f32));
+                }
+            }
+            for x in 0..width {
+                if !toroidal || !down[(height - 1) * width + x] {
+                    line((x as f32, 0.0), ((x + 1) as f32, 0.0));
+                }
+            }
+        }
+        MazeStyle::Passages => {
+            for y in 0..height {
+                for x in 0..width {
+                    let ix = y * width + x;
+                    let (cx, cy) = (x as f32 + 0.5, y as f32 + 0.5);
+
+                    if right[ix] {
+                        line((cx, cy), (cx + 1.0, cy));
+                        if x + 1 == width {
+                            line((cx - width as f32, cy), (cx + 1.0 - width as f32, cy));
+                        }
+                    }
+                    if down[ix] {
+                        line((cx, cy), (cx, cy + 1.0));
+                        if y + 1 == height {
+                            line((cx, cy - height as f32), (cx, cy + 1.0 - height as f32));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    doc = doc.add(
+        Path::new()
+            .set("d", path.trim_start())
+            .set("fill", "none")
+            .set("stroke", stroke_color)
+            .set("stroke-opacity", stroke_opacity)
+            .set("stroke-width", stroke_width)
+            .set("stroke-linecap", stroke_linecap),
+    );
+
+    doc
+}
+
+/// mosaic squares
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/mosaic_squares.svg)
+///
+/// ```
+/// use geopattern::mosaic_squares;
+///
+/// let c = mosaic_squares(
+///     20.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#ddd", 0.2),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn mosaic_squares(
+    side: f32,
+    (width, height): (usize, usize),
+    fill_outer: &[(&str, f32)],
+    fill_inner: &[(&str, f32)],
+    stroke: (&str, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(fill_outer.len(), width * height);
+    debug_assert_eq!(fill_inner.len(), width * height);
+
+    let mut doc = create_document(
+        (side * width as f32 * 2.0, side * height as f32 * 2.0),
+        background_color,
+    );
+
+    let draw_outer_tile = |mut doc: Document, x: f32, y: f32, ix: usize| {
+        let points = format!("0,0,{},{},0,{},0,0", side, side, side);
+
+        let polyline = Polyline::new()
+            .set("points", points)
+            .set("fill", fill_outer[ix].0)
+            .set("fill-opacity", fill_outer[ix].1)
+            .set("stroke", stroke.0)
+            .set("stroke-opacity", stroke.1);
+
+        doc = doc.add(polyline.clone().set(
+            "transform",
+            format!("translate({}, {}) scale(1, -1)", x, y + side),
+        ));
+
+        doc = doc.add(polyline.clone().set(
+            "transform",
+            format!("translate({}, {}) scale(-1, -1)", x + side * 2.0, y + side),
+        ));
+
+        doc = doc.add(polyline.clone().set(
+            "transform",
+            format!("translate({}, {}) scale(1, 1)", x, y + side),
+        ));
+
+        doc = doc.add(polyline.set(
+            "transform",
+            format!("translate({}, {}) scale(-1, 1)", x + side * 2.0, y + side),
+        ));
+
+        doc
+    };
+
+    let draw_inner_tile = |mut doc: Document, x: f32, y: f32, ix: usize| {
+        let points = format!("0,0,{},{},0,{},0,0", side, side, side);
+
+        let polyline = Polyline::new()
+            .set("points", points.as_str())
+            .set("fill", fill_outer[ix].0)
+            .set("fill-opacity", fill_outer[ix].1)
+            .set("stroke", stroke.0)
+            .set("stroke-opacity", stroke.1);
+
+        doc = doc.add(polyline.clone().set(
+            "transform",
+            format!("translate({}, {}) scale(-1, 1)", x + side, y),
+        ));
+
+        doc = doc.add(polyline.set(
+            "transform",
+            format!("translate({}, {}) scale(1, -1)", x + side, y + side * 2.0),
+        ));
+
+        let polyline = Polyline::new()
+            .set("points", points)
+            .set("fill", fill_inner[ix].0)
+            .set("fill-opacity", fill_inner[ix].1)
+            .set("stroke", stroke.0)
+            .set("stroke-opacity", stroke.1);
+
+        doc = doc.add(polyline.clone().set(
+            "transform",
+            format!("translate({}, {}) scale(-1, -1)", x + side, y + side * 2.0),
+        ));
+
+        doc = doc.add(polyline.set(
+            "transform",
+            format!("translate({}, {}) scale(1, 1)", x + side, y),
+        ));
+
+        doc
+    };
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            match x % 2 {
+                1 => match y % 2 {
+                    1 => {
+                        doc = draw_outer_tile(
+                            doc,
+                            (x as f32) * side * 2.0,
+                            (y as f32) * side * 2.0,
+                            ix,
+                        );
+                    }
+                    _ => {
+                        doc = draw_inner_tile(
+                            doc,
+                            (x as f32) * side * 2.0,
+                            (y as f32) * side * 2.0,
+                            ix,
+                        );
+                    }
+                },
+                _ => match y % 2 {
+                    1 => {
+                        doc = draw_inner_tile(
+                            doc,
+                            (x as f32) * side * 2.0,
+                            (y as f32) * side * 2.0,
+                            ix,
+                        );
+                    }
+                    _ => {
+                        doc = draw_outer_tile(
+                            doc,
+                            (x as f32) * side * 2.0,
+                            (y as f32) * side * 2.0,
+                            ix,
+                        );
+                    }
+                },
+            }
+        }
+    }
+
+    doc
+}
+
+/// nested squares
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/nested_squares.svg)
+///
+/// TODO: make `outer_side` independent of `inner_side`, currently evaluates to `7 * inner_side`
+///
+/// ```
+/// use geopattern::nested_squares;
+///
+/// let c = nested_squares(
+///     60.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn nested_squares(
+    inner_side: f32,
+    (width, height): (usize, usize),
+    stroke_outer: &[(&str, f32)],
+    fill_inner: &[(&str, f32)],
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(fill_inner.len(), width * height);
+    debug_assert_eq!(stroke_outer.len(), width * height);
+
+    let outer_side = inner_side * 7.0;
+
+    let mut doc = create_document(
+        (
+            (inner_side * 2.0 + outer_side) * width as f32,
+            (inner_side * 2.0 + outer_side) * height as f32,
+        ),
+        background_color,
+    );
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            // outer
+            doc = doc.add(
+                Rectangle::new()
+                    .set(
+                        "x",
+                        (x as f32) * outer_side + (x as f32) * inner_side * 2.0 + inner_side / 2.0,
+                    )
+                    .set(
+                        "y",
+                        (y as f32) * outer_side + (y as f32) * inner_side * 2.0 + inner_side / 2.0,
+                    )
+                    .set("width", outer_side)
+                    .set("height", outer_side)
+                    .set("fill", "none")
+                    .set("stroke", stroke_outer[ix].0)
+                    .set("stroke-width", inner_side)
+                    .set("stroke-opacity", stroke_outer[ix].1),
+            );
+
+            // inner
+            doc = doc.add(
+                Rectangle::new()
+                    .set(
+                        "x",
+                        (x as f32) * outer_side
+                            + (x as f32) * inner_side * 2.0
+                            + inner_side / 2.0
+                            + inner_side * 2.0,
+                    )
+                    .set(
+                        "y",
+                        (y as f32) * outer_side
+                            + (y as f32) * inner_side * 2.0
+                            + inner_side / 2.0
+                            + inner_side * 2.0,
+                    )
+                    .set("width", inner_side * 3.0)
+                    .set("height", inner_side * 3.0)
+                    .set("fill", "none")
+                    .set("stroke", fill_inner[ix].0)
+                    .set("stroke", fill_inner[ix].0)
+                    .set("stroke-width", inner_side)
+                    .set("stroke-opacity", fill_inner[ix].1),
+            );
+        }
+    }
+
+    doc
+}
+
+/// octagons
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/octagons.svg)
+///
+/// ```
+/// use geopattern::octagons;
+///
+/// let c = octagons(
+///     60.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#ddd", 0.2),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn octagons(
+    side: f32,
+    (width, height): (usize, usize),
+    fill: &[(&str, f32)],
+    stroke: (&str, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(fill.len(), width * height);
+
+    let mut doc = create_document(
+        (side * width as f32, side * height as f32),
+        background_color,
+    );
+
+    let c = 0.33 * side;
+    let points = format!(
+        "{},0,{},0,{},{},{},{},{},{},{},{},0,{},0,{},{},0",
+        c,
+        side - c,
+        side,
+        c,
+        side,
+        side - c,
+        side - c,
+        side,
+        c,
+        side,
+        side - c,
+        c,
+        c
+    );
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            doc = doc.add(
+                Polyline::new()
+                    .set("points", points.as_str())
+                    .set("fill", fill[ix].0)
+                    .set("fill-opacity", fill[ix].1)
+                    .set("stroke", stroke.0)
+                    .set("stroke-opacity", stroke.1)
+                    .set(
+                        "transform",
+                        format!("translate({}, {})", (x as f32) * side, (y as f32) * side),
+                    ),
+            );
+        }
+    }
+
+    doc
+}
+
+/// overlapping circles
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/overlapping_circles.svg)
+///
+/// ```
+/// use geopattern::overlapping_circles;
+///
+/// let c = overlapping_circles(
+///     60.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn overlapping_circles(
+    radius: f32,
+    (width, height): (usize, usize),
+    fill: &[(&str, f32)],
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(fill.len(), width * height);
+
+    let mut doc = create_document(
+        (radius * width as f32, radius * height as f32),
+        background_color,
+    );
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            doc = doc.add(
+                Circle::new()
+                    .set("cx", x as f32 * radius)
+                    .set("cy", y as f32 * radius)
+                    .set("r", radius)
+                    .set("fill", fill[ix].0)
+                    .set("fill-opacity", fill[ix].1),
+            );
+
+            if x == 0 {
+                doc = doc.add(
+                    Circle::new()
+                        .set("cx", width as f32 * radius)
+                        .set("cy", y as f32 * radius)
+                        .set("r", radius)
+                        .set("fill", fill[ix].0)
+                        .set("fill-opacity", fill[ix].1),
+                )
+            }
+
+            if y == 0 {
+                doc = doc.add(
+                    Circle::new()
+                        .set("cx", x as f32 * radius)
+                        .set("cy", height as f32 * radius)
+                        .set("r", radius)
+                        .set("fill", fill[ix].0)
+                        .set("fill-opacity", fill[ix].1),
+                )
+            }
+
+            if x == 0 && y == 0 {
+                doc = doc.add(
+                    Circle::new()
+                        .set("cx", width as f32 * radius)
+                        .set("cy", height as f32 * radius)
+                        .set("r", radius)
+                        .set("fill", fill[ix].0)
+                        .set("fill-opacity", fill[ix].1),
+                )
+            }
+        }
+    }
+
+    doc
+}
+
+/// overlapping rings
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/overlapping_rings.svg)
+///
+/// TODO: consider having an `outer_radius`?
+///
+/// ```
+/// use geopattern::overlapping_rings;
+///
+/// let c = overlapping_rings(
+///     60.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn overlapping_rings(
+    radius: f32,
+    (width, height): (usize, usize),
+    stroke: &[(&str, f32)],
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(stroke.len(), width * height);
+
+    let mut doc = create_document(
+        (radius * width as f32, radius * height as f32),
+        background_color,
+    );
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            doc = doc.add(
+                Circle::new()
+                    .set("cx", x as f32 * radius)
+                    .set("cy", y as f32 * radius)
+                    .set("r", radius - radius / 8.0)
+                    .set("fill", "none")
+                    .set("stroke", stroke[ix].0)
+                    .set("stroke-opacity", stroke[ix].1)
+                    .set("stroke-width", radius / 4.0),
+            );
+
+            if x == 0 {
+                doc = doc.add(
+                    Circle::new()
+                        .set("cx", width as f32 * radius)
+                        .set("cy", (y as f32) * radius)
+                        .set("r", radius - radius / 8.0)
+                        .set("fill", "none")
+                        .set("stroke", stroke[ix].0)
+                        .set("stroke-opacity", stroke[ix].1)
+                        .set("stroke-width", radius / 4.0),
+                )
+            }
+
+            if y == 0 {
+                doc = doc.add(
+                    Circle::new()
+                        .set("cx", (x as f32) * radius)
+                        .set("cy", height as f32 * radius)
+                        .set("r", radius - radius / 8.0)
+                        .set("fill", "none")
+                        .set("stroke", stroke[ix].0)
+                        .set("stroke-opacity", stroke[ix].1)
+                        .set("stroke-width", radius / 4.0),
+                )
+            }
+
+            if x == 0 && y == 0 {
+                doc = doc.add(
+                    Circle::new()
+                        .set("cx", width as f32 * radius)
+                        .set("cy", height as f32 * radius)
+                        .set("r", radius - radius / 8.0)
+                        .set("fill", "none")
+                        .set("stroke", stroke[ix].0)
+                        .set("stroke-opacity", stroke[ix].1)
+                        .set("stroke-width", radius / 4.0),
+                )
+            }
+        }
+    }
+
+    doc
+}
+
+/// enum to identify the axis along which the splits need to be made
+pub enum PietMondrianSplitType {
+    /// X direction
+    X,
+    /// Y direction
+    Y,
+}
+
+/// Piet Mondrian
+///
+/// https://generativeartistry.com/tutorials/piet-mondrian/
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/piet_mondrian.svg)
+///
+/// ```
+/// use geopattern::{piet_mondrian, PietMondrianSplitType};
+///
+/// let c = piet_mondrian(
+///     &vec![
+///         (PietMondrianSplitType::X, 80.0),
+///         (PietMondrianSplitType::Y, 220.0),
+///     ],
+///     (300.0, 300.0),
+///     &vec![("#FFF", 1.0), ("#00F", 1.0), ("#F00", 1.0), ("#FFF", 1.0)],
+///     ("#222", 8.0, 1.0),
+///     "#FFF",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn piet_mondrian(
+    splits: &[(PietMondrianSplitType, f32)],
+    (width, height): (f32, f32),
+    fill: &[(&str, f32)],
+    stroke: (&str, f32, f32),
+    background_color: &str,
+) -> Document {
+    let squares = piet_mondrian_layout(splits, (width, height), (0, 1.0));
+
+    debug_assert_eq!(squares.len(), fill.len());
+
+    piet_mondrian_with(
+        &squares,
+        (width, height),
+        |i, _| fill[i],
+        stroke,
+        background_color,
+    )
+}
+
+/// the `(x, y, width, height)` of the rectangles `piet_mondrian` draws for `splits`
+///
+/// Each rectangle crossed by a split is split by it with a chance of `chance`, decided from
+/// `seed`. `piet_mondrian` splits all of them, with a `chance` of `1.0`, and the tutorial splits
+/// half of them.
+///
+/// ```
+/// use geopattern::{piet_mondrian_layout, PietMondrianSplitType};
+///
+/// let rectangles = piet_mondrian_layout(
+///     &vec![
+///         (PietMondrianSplitType::X, 80.0),
+///         (PietMondrianSplitType::Y, 220.0),
+///     ],
+///     (300.0, 300.0),
+///     (0, 1.0),
+/// );
+///
+/// assert_eq!(rectangles.len(), 4);
+/// assert_eq!(rectangles[0], (0.0, 0.0, 80.0, 220.0));
+/// ```
+pub fn piet_mondrian_layout(
+    splits: &[(PietMondrianSplitType, f32)],
+    (width, height): (f32, f32),
+    (seed, chance): (u64, f32),
+) -> Vec<(f32, f32, f32, f32)> {
+    let mut rng = Rng::new(seed);
+    let mut squares = vec![(0.0, 0.0, width, height)];
+
+    for split in splits {
+        squares = piet_mondrian_split(squares, split, |_| chance >= 1.0 || rng.next_f32() < chance);
+    }
+
+    squares
+}
+
+/// Piet Mondrian, for rectangles from `piet_mondrian_layout` or `piet_mondrian_rectangles`
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/piet_mondrian_with.svg)
+///
+/// `fill` gives the colour and opacity of each rectangle from its index and `(x, y, width,
+/// height)`.
+///
+/// ```
+/// use geopattern::{piet_mondrian_rectangles, piet_mondrian_with};
+///
+/// let rectangles = piet_mondrian_rectangles(7, (300.0, 300.0), 50.0);
+///
+/// let c = piet_mondrian_with(
+///     &rectangles,
+///     (300.0, 300.0),
+///     |_, (_, _, w, h)| if w * h > 10000.0 { ("#F00", 1.0) } else { ("#FFF", 1.0) },
+///     ("#222", 8.0, 1.0),
+///     "#FFF",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn piet_mondrian_with<F, S>(
+    rectangles: &[(f32, f32, f32, f32)],
+    (width, height): (f32, f32),
+    mut fill: F,
+    stroke: (&str, f32, f32),
+    background_color: &str,
+) -> Document
+where
+    F: FnMut(usize, (f32, f32, f32, f32)) -> (S, f32),
+    S: Into<Value>,
+{
+    let mut doc = create_document((width, height), background_color);
+
+    for (i, square) in rectangles.iter().enumerate() {
+        let (x, y, w, h) = *square;
+        let (fill_color, fill_opacity) = fill(i, *square);
+
+        doc = doc.add(
+            Rectangle::new()
+                .set("x", x)
+                .set("y", y)
+                .set("width", w)
+                .set("height", h)
+                .set("fill", fill_color)
+                .set("fill-opacity", fill_opacity)
+                .set("stroke", stroke.0)
+                .set("stroke-width", stroke.1)
+                .set("stroke-opacity", stroke.2),
+        );
+    }
+
+    doc
+}
+
+/// Piet Mondrian, with the splits and colours chosen from `seed`
+///
+/// https://generativeartistry.com/tutorials/piet-mondrian/
+///
+/// The rectangles are the ones from `piet_mondrian_rectangles`, and the fill is from
+/// `piet_mondrian_palette` with `proportions` of red, blue and yellow.
+///
+/// ```
+/// use geopattern::piet_mondrian_random;
+///
+/// let c = piet_mondrian_random(
+///     7,
+///     (300.0, 300.0),
+///     50.0,
+///     (0.1, 0.1, 0.1),
+///     ("#222", 8.0, 1.0),
+///     "#FFF",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn piet_mondrian_random(
+    seed: u64,
+    (width, height): (f32, f32),
+    step: f32,
+    proportions: (f32, f32, f32),
+    stroke: (&str, f32, f32),
+    background_color: &str,
+) -> Document {
+    let squares = piet_mondrian_rectangles(seed, (width, height), step);
+    let fill = piet_mondrian_palette(seed.wrapping_add(1), squares.len(), proportions);
+
+    piet_mondrian_with(
+        &squares,
+        (width, height),
+        |i, _| fill[i],
+        stroke,
+        background_color,
+    )
+}
+
+/// the `(x, y, width, height)` of the rectangles in a Piet Mondrian composition
+///
+/// As in the tutorial, a line is drawn every `step` along each axis, alternating between the two,
+/// and every rectangle it crosses is split by it with a chance of one half. `step` must be
+/// positive; otherwise the whole canvas is returned as a single rectangle.
+///
+/// ```
+/// use geopattern::piet_mondrian_rectangles;
+///
+/// let rectangles = piet_mondrian_rectangles(7, (300.0, 300.0), 50.0);
+///
+/// let area = rectangles.iter().map(|(_, _, w, h)| w * h).sum::<f32>();
+/// assert!((area - 300.0 * 300.0).abs() < 1.0);
+/// ```
+pub fn piet_mondrian_rectangles(
+    seed: u64,
+    (width, height): (f32, f32),
+    step: f32,
+) -> Vec<(f32, f32, f32, f32)> {
+    debug_assert!(step > 0.0);
+
+    if step.is_nan() || step <= 0.0 {
+        return vec![(0.0, 0.0, width, height)];
+    }
+
+    let mut splits = vec![];
+
+    let mut location = step;
+    while location < width || location < height {
+        splits.push((PietMondrianSplitType::X, location));
+        splits.push((PietMondrianSplitType::Y, location));
+
+        location += step;
+    }
+
+    piet_mondrian_layout(&splits, (width, height), (seed, 0.5))
+}
+
+/// the classic fill for `count` Piet Mondrian rectangles
+///
+/// `proportions` are the fractions of the rectangles that are red, blue and yellow, chosen at
+/// random from `seed`, and the rest are white.
+///
+/// ```
+/// use geopattern::piet_mondrian_palette;
+///
+/// let fill = piet_mondrian_palette(7, 10, (0.1, 0.2, 0.1));
+///
+/// assert_eq!(fill.len(), 10);
+/// assert_eq!(fill.iter().filter(|(c, _)| *c == "#1356A2").count(), 2);
+/// ```
+pub fn piet_mondrian_palette(
+    seed: u64,
+    count: usize,
+    (red, blue, yellow): (f32, f32, f32),
+) -> Vec<(&'static str, f32)> {
+    let mut indices = (0..count).collect::<Vec<usize>>();
+    Rng::new(seed).shuffle(&mut indices);
+
+    let mut fill = vec![("#F2F2F2", 1.0); count];
+    let mut indices = indices.into_iter();
+
+    for (color, proportion) in &[("#D40920", red), ("#1356A2", blue), ("#F7D842", yellow)] {
+        let n = (proportion * count as f32).round() as usize;
+
+        for i in indices.by_ref().take(n) {
+            fill[i] = (*color, 1.0);
+        }
+    }
+
+    fill
+}
+
+/// splits every rectangle crossed by `split` that `choose` picks
+fn piet_mondrian_split<F>(
+    squares: Vec<(f32, f32, f32, f32)>,
+    (split_type, split_location): &(PietMondrianSplitType, f32),
+    mut choose: F,
+) -> Vec<(f32, f32, f32, f32)>
+where
+    F: FnMut((f32, f32, f32, f32)) -> bool,
+{
+    let mut new_squares = vec![];
+
+    for s in squares {
+        let (x, y, w, h) = s;
+        match split_type {
+            PietMondrianSplitType::X => {
+                if x < *split_location && x + w > *split_location && choose(s) {
+                    new_squares.push((x, y, w - (x + w - split_location), h));
+                    new_squares.push((*split_location, y, x + w - split_location, h));
+                } else {
+                    new_squares.push(s);
+                }
+            }
+            PietMondrianSplitType::Y => {
+                if y < *split_location && y + h > *split_location && choose(s) {
+                    new_squares.push((x, y, w, h - (y + h - split_location)));
+                    new_squares.push((x, *split_location, w, y + h - split_location));
+                } else {
+                    new_squares.push(s);
+                }
+            }
+        }
+    }
+
+    new_squares
+}
+
+/// plaid
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/plaid.svg)
+///
+/// The stripes are blended by their opacity, see `tartan` for stripes woven in a twill.
+///
+/// ```
+/// use geopattern::plaid;
+///
+/// let c = plaid(
+///     &(0..4).map(|v| 5.0 + v as f32 * 4.0).collect::<Vec<f32>>(),
+///     &(0..4).map(|v| 3.0 + v as f32 * 7.0).collect::<Vec<f32>>(),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn plaid(
+    distances: &[f32],
+    sizes: &[f32],
+    fill: &[(&str, f32)],
+    background_color: &str,
+) -> Document {
+    let n = distances.len();
+
+    debug_assert_eq!(sizes.len(), n);
+    debug_assert_eq!(fill.len(), n);
+
+    let (mut w, mut h) = (0.0, 0.0);
+
+    let mut doc = create_document((0, 0), background_color);
+
+    for i in 0..n {
+        h += distances[i];
+
+        doc = doc.add(
+            Rectangle::new()
+                .set("x", 0)
+                .set("y", h)
+                .set("width", "100%")
+                .set("height", sizes[i])
+                .set("opacity", fill[i].1)
+                .set("fill", fill[i].0),
+        );
+
+        h += sizes[i];
+    }
+
+    for i in 0..n {
+        w += distances[i];
+
+        doc = doc.add(
+            Rectangle::new()
+                .set("x", w)
+                .set("y", 0)
+                .set("width", sizes[i])
+                .set("height", "100%")
+                .set("opacity", fill[i].1)
+                .set("fill", fill[i].0),
+        );
+
+        w += sizes[i];
+    }
+
+    doc.set("width", w).set("height", h)
+}
+
+fn plus(side: f32) -> (Rectangle, Rectangle) {
+    (
+        Rectangle::new()
+            .set("x", side)
+            .set("y", 0)
+            .set("width", side)
+            .set("height", side * 3.0),
+        Rectangle::new()
+            .set("x", 0)
+            .set("y", side)
+            .set("width", side * 3.0)
+            .set("height", side),
+    )
+}
+
+/// plus_signs
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/plus_signs.svg)
+///
+/// ```
+/// use geopattern::plus_signs;
+///
+/// let c = plus_signs(
+///     60.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#ddd", 0.2),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn plus_signs(
+    side: f32,
+    (width, height): (usize, usize),
+    fill: &[(&str, f32)],
+    stroke: (&str, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(fill.len(), width * height);
+
+    let mut doc = create_document(
+        (side * 2.0 * width as f32, side * 2.0 * height as f32),
+        background_color,
+    );
+
+    let length = side * 3.0;
+
+    let rects = plus(side);
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            let dx = (y % 2) as f32;
+
+            let g = Group::new()
+                .set("fill", fill[ix].0)
+                .set("stroke", stroke.0)
+                .set("stroke-opacity", stroke.1)
+                .set("fill-opacity", fill[ix].1)
+                .add(rects.0.clone())
+                .add(rects.1.clone());
+
+            doc = doc.add(g.clone().set(
+                "transform",
+                format!(
+                    "translate({}, {})",
+                    (x as f32) * (length - side) + dx * side - side,
+                    (y as f32) * (length - side) - length / 2.0
+                ),
+            ));
+
+            if x == 0 {
+                doc = doc.add(g.clone().set(
+                    "transform",
+                    format!(
+                        "translate({}, {})",
+                        4.0 * length - (x as f32) * side + dx * side - side,
+                        (y as f32) * (length - side) - length / 2.0
+                    ),
+                ));
+            }
+
+            if y == 0 {
+                doc = doc.add(g.clone().set(
+                    "transform",
+                    format!(
+                        "translate({}, {})",
+                        (x as f32) * (length - side) + dx * side - side,
+                        4.0 * length - (y as f32) * side - length / 2.0
+                    ),
+                ));
+            }
+
+            if x == 0 && y == 0 {
+                doc = doc.add(g.clone().set(
+                    "transform",
+                    format!(
+                        "translate({}, {})",
+                        4.0 * length - (x as f32) * side + dx * side - side,
+                        4.0 * length - (y as f32) * side - length / 2.0
+                    ),
+                ));
+            }
+        }
+    }
+
+    doc
+}
+
+/// concentric circles of `radius` divided into `rings`, from the outside in, with the colours of
+/// `fill` starting at `ix * rings`
+fn concentric_rings(
+    (cx, cy): (f32, f32),
+    radius: f32,
+    rings: usize,
+    fill: &[(&str, f32)],
+    ix: usize,
+    (stroke_color, stroke_opacity): (&str, f32),
+) -> Group {
+    let mut g = Group::new()
+        .set("stroke", stroke_color)
+        .set("stroke-opacity", stroke_opacity);
+
+    for k in 0..rings {
+        let (color, opacity) = fill[ix * rings + k];
+
+        g = g.add(
+            Circle::new()
+                .set("cx", cx)
+                .set("cy", cy)
+                .set("r", radius * (rings - k) as f32 / rings as f32)
+                .set("fill", color)
+                .set("fill-opacity", opacity),
+        );
+    }
+
+    g
+}
+
+/// Scales
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/scales.svg)
+///
+/// Rows of overlapping circles of `radius`, every other row offset by half a scale, drawn from the
+/// bottom up so each row covers the upper half of the row below it. Every scale is divided into
+/// `rings` concentric rings, so `fill` has `width * height * rings` colours, `rings` for each
+/// scale from the outside in. `height` must be even.
+///
+/// ```
+/// use geopattern::scales;
+///
+/// let c = scales(
+///     20.0,
+///     (2, 2),
+///     2,
+///     &(0..8)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 8.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#000", 0.2),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn scales(
+    radius: f32,
+    (width, height): (usize, usize),
+    rings: usize,
+    fill: &[(&str, f32)],
+    stroke: (&str, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(height % 2, 0);
+    debug_assert_eq!(fill.len(), width * height * rings);
+
+    let mut doc = create_document(
+        (radius * 2.0 * width as f32, radius * height as f32),
+        background_color,
+    );
+
+    if width == 0 || height == 0 {
+        return doc;
+    }
+
+    // from the bottom up, one extra row and column on each side for the scales that wrap around
+    for y in (-1..=height as isize + 1).rev() {
+        for x in -1..=width as isize {
+            let ix = y.rem_euclid(height as isize) as usize * width
+                + x.rem_euclid(width as isize) as usize;
+            let dx = if y.rem_euclid(2) == 1 { radius } else { 0.0 };
+
+            doc = doc.add(concentric_rings(
+                (x as f32 * radius * 2.0 + dx, y as f32 * radius),
+                radius,
+                rings,
+                fill,
+                ix,
+                stroke,
+            ));
+        }
+    }
+
+    doc
+}
+
+/// Seigaiha
+///
+/// https://en.wikipedia.org/wiki/Seigaiha
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/seigaiha.svg)
+///
+/// Rows of concentric arcs of `radius`, every other row offset by half a wave and half a radius
+/// below the last, with each row drawn over the lower half of the ones above it. Every wave is
+/// divided into `rings` concentric rings, so `fill` has `width * height * rings` colours, `rings`
+/// for each wave from the outside in. `height` must be even.
+///
+/// ```
+/// use geopattern::seigaiha;
+///
+/// let c = seigaiha(
+///     20.0,
+///     (2, 2),
+///     4,
+///     &(0..16)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 16.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#000", 0.2),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn seigaiha(
+    radius: f32,
+    (width, height): (usize, usize),
+    rings: usize,
+    fill: &[(&str, f32)],
+    stroke: (&str, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(height % 2, 0);
+    debug_assert_eq!(fill.len(), width * height * rings);
+
+    let mut doc = create_document(
+        (radius * 2.0 * width as f32, radius / 2.0 * height as f32),
+        background_color,
+    );
+
+    if width == 0 || height == 0 {
+        return doc;
+    }
+
+    // from the top down, two extra rows and one column on each side for the waves that wrap around
+    for y in -2..=height as isize + 2 {
+        for x in -1..=width as isize {
+            let ix = y.rem_euclid(height as isize) as usize * width
+                + x.rem_euclid(width as isize) as usize;
+            let dx = if y.rem_euclid(2) == 1 { radius } else { 0.0 };
+
+            doc = doc.add(concentric_rings(
+                (x as f32 * radius * 2.0 + dx, y as f32 * radius / 2.0),
+                radius,
+                rings,
+                fill,
+                ix,
+                stroke,
+            ));
+        }
+    }
+
+    doc
+}
+
+/// sine waves
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/sine_waves.svg)
+///
+/// See `sine_waves_with` for other orientations and shapes, and a period for each line.
+///
+/// ```
+/// use geopattern::sine_waves;
+///
+/// let c = sine_waves(
+///     120.0,
+///     80.0,
+///     20.0,
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn sine_waves(
+    period: f32,
+    a: f32,
+    ww: f32,
+    stroke: &[(&str, f32)],
+    background_color: &str,
+) -> Document {
+    let n = stroke.len();
+
+    let mut doc = create_document((period, ww * n as f32), background_color);
+
+    for (i, s) in stroke.iter().enumerate() {
+        let xoff = (period / 4.0) * 0.7;
+
+        let path = Path::new()
+            .set(
+                "d",
+                format!(
+                    "M0 {} C {} 0, {} 0, {} {} S {} {}, {} {} S {} 0, {}, {}",
+                    a,
+                    xoff,
+                    period / 2.0 - xoff,
+                    period / 2.0,
+                    a,
+                    period - xoff,
+                    a * 2.0,
+                    period,
+                    a,
+                    period * 1.5 - xoff,
+                    period * 1.5,
+                    a
+                ),
+            )
+            .set("fill", "none")
+            .set("stroke", s.0)
+            .set("stroke-opacity", s.1)
+            .set("stroke-width", ww);
+
+        doc = doc.add(path.clone().set(
+            "transform",
+            format!(
+                "translate(-{}, {})",
+                period / 4.0,
+                (ww * i as f32) - (a * 1.5)
+            ),
+        ));
+
+        doc = doc.add(path.clone().set(
+            "transform",
+            format!(
+                "translate(-{}, {})",
+                period / 4.0,
+                (ww * i as f32) - (a * 1.5) + ww * n as f32
+            ),
+        ));
+    }
+
+    doc
+}
+
+/// enum to identify the direction of the lines in `sine_waves_with`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SineWavesOrientation {
+    /// left to right, as in `sine_waves`
+    Horizontal,
+    /// top to bottom
+    Vertical,
+    /// top left to bottom right
+    Diagonal,
+}
+
+/// enum to identify the shape of the waves in `sine_waves_with`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SineWavesShape {
+    /// a sine approximated by a cubic bezier in each half period, as in `sine_waves`
+    Bezier,
+    /// a sine sampled 16 times in each period
+    Sine,
+    /// a square wave
+    Square,
+    /// a triangle wave
+    Triangle,
+    /// a sawtooth wave, rising through each period
+    Sawtooth,
+}
+
+/// sine waves, with a shape, amplitude and period for each line
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/sine_waves_with.svg)
+///
+/// Each of `waves` is `(amplitude, cycles, phase)` for a line of `stroke`, where `cycles` is the
+/// number of periods in `length` and `phase` is in periods. The lines are `line_width` wide and
+/// `line_width` apart.
+///
+/// The pattern is `length` along the lines, except for `Diagonal`, where it is a square of side
+/// `length * sqrt(2)` and `length` is rounded to a multiple of `line_width * stroke.len()` so the
+/// lines wrap around its edges.
+///
+/// ```
+/// use geopattern::{sine_waves_with, SineWavesOrientation, SineWavesShape};
+///
+/// let c = sine_waves_with(
+///     120.0,
+///     20.0,
+///     (SineWavesOrientation::Vertical, SineWavesShape::Triangle),
+///     &(0..4)
+///         .map(|v| (10.0 + v as f32 * 5.0, 1 + v, v as f32 / 4.0))
+///         .collect::<Vec<(f32, usize, f32)>>(),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn sine_waves_with(
+    length: f32,
+    line_width: f32,
+    (orientation, shape): (SineWavesOrientation, SineWavesShape),
+    waves: &[(f32, usize, f32)],
+    stroke: &[(&str, f32)],
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(waves.len(), stroke.len());
+
+    let band = line_width * stroke.len() as f32;
+    let root2 = std::f32::consts::SQRT_2;
+
+    let length = match orientation {
+        SineWavesOrientation::Diagonal => (length / band).round().max(1.0) * band,
+        _ => length,
+    };
+
+    let (width, height) = match orientation {
+        SineWavesOrientation::Horizontal => (length, band),
+        SineWavesOrientation::Vertical => (band, length),
+        SineWavesOrientation::Diagonal => (length * root2, length * root2),
+    };
+
+    // the lines are laid out along u and across v, and mapped to x and y
+    let to_xy = |(u, v): (f32, f32)| match orientation {
+        SineWavesOrientation::Horizontal => (u, v),
+        SineWavesOrientation::Vertical => (v, u),
+        SineWavesOrientation::Diagonal => ((u - v) / root2, (u + v) / root2),
+    };
+
+    let to_uv = |(x, y): (f32, f32)| match orientation {
+        SineWavesOrientation::Horizontal => (x, y),
+        SineWavesOrientation::Vertical => (y, x),
+        SineWavesOrientation::Diagonal => ((x + y) / root2, (y - x) / root2),
+    };
+
+    let corners = [(0.0, 0.0), (width, 0.0), (0.0, height), (width, height)]
+        .iter()
+        .map(|p| to_uv(*p))
+        .collect::<Vec<(f32, f32)>>();
+
+    let bounds = |f: fn(f32, f32) -> f32, g: fn(&(f32, f32)) -> f32| {
+        corners.iter().map(g).fold(g(&corners[0]), f)
+    };
+
+    let (umin, umax) = (bounds(f32::min, |p| p.0), bounds(f32::max, |p| p.0));
+    let (vmin, vmax) = (bounds(f32::min, |p| p.1), bounds(f32::max, |p| p.1));
+
+    // one period, from t = 0 to t = 1, with the cubic control points for `Bezier`
+    let period_points = match shape {
+        SineWavesShape::Bezier => vec![
+            (0.175, 1.0),
+            (0.325, 1.0),
+            (0.5, 0.0),
+            (0.675, -1.0),
+            (0.825, -1.0),
+            (1.0, 0.0),
+        ],
+        SineWavesShape::Sine => (1..=16)
+            .map(|j| {
+                let t = j as f32 / 16.0;
+                (t, (2.0 * std::f32::consts::PI * t).sin())
+            })
+            .collect(),
+        SineWavesShape::Square => vec![(0.5, 1.0), (0.5, -1.0), (1.0, -1.0), (1.0, 1.0)],
+        SineWavesShape::Triangle => vec![(0.25, 1.0), (0.75, -1.0), (1.0, 0.0)],
+        SineWavesShape::Sawtooth => vec![(1.0, 1.0), (1.0, -1.0)],
+    };
+
+    let start = match shape {
+        SineWavesShape::Square => 1.0,
+        SineWavesShape::Sawtooth => -1.0,
+        _ => 0.0,
+    };
+
+    let mut doc = create_document((width, height), background_color);
+
+    for (i, ((amplitude, cycles, phase), (stroke_color, stroke_opacity))) in
+        waves.iter().zip(stroke.iter()).enumerate()
+    {
+        let period = length / (*cycles).max(1) as f32;
+        let margin = amplitude.abs() + line_width;
+
+        let v0 = line_width * (i as f32 + 0.5);
+        let first = ((vmin - margin - v0) / band).floor() as i64;
+        let last = ((vmax + margin - v0) / band).ceil() as i64;
+
+        // whole periods from before the start of the pattern, shifted back by the phase
+        let u0 =
+            ((umin - margin) / length).floor() * length - (phase.rem_euclid(1.0) + 1.0) * period;
+        let periods = ((umax + margin - u0) / period).ceil() as usize;
+
+        let mut data = String::new();
+
+        for k in first..=last {
+            let v = v0 + k as f32 * band;
+            let point = |t: f32, a: f32| {
+                let (x, y) = to_xy((u0 + t * period, v + a * amplitude));
+                format!("{},{}", x, y)
+            };
+
+            data.push_str(&format!("M{}", point(0.0, start)));
+
+            for p in 0..periods {
+                let points = period_points
+                    .iter()
+                    .map(|(t, a)| point(p as f32 + t, *a))
+                    .collect::<Vec<String>>();
+
+                match shape {
+                    SineWavesShape::Bezier => data.push_str(&format!(
+                        "C{} {} {}C{} {} {}",
+                        points[0], points[1], points[2], points[3], points[4], points[5]
+                    )),
+                    _ => data.push_str(&format!("L{}", points.join(" "))),
+                }
+            }
+        }
+
+        doc = doc.add(
+            Path::new()
+                .set("d", data)
+                .set("fill", "none")
+                .set("stroke", *stroke_color)
+                .set("stroke-opacity", *stroke_opacity)
+                .set("stroke-width", line_width),
+        );
+    }
+
+    doc
+}
+
+/// enum to identify the curve drawn by `space_filling_curve`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpaceFillingCurve {
+    /// covers a `2^order` square grid
+    Hilbert,
+    /// covers a `3^order` square grid
+    Peano,
+    /// closed variant of the Hilbert curve, covers a `2^order` square grid
+    Moore,
+    /// the flowsnake, on a hexagonal grid
+    Gosper,
+}
+
+/// Space Filling Curve
+///
+/// https://en.wikipedia.org/wiki/Space-filling_curve
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/space_filling_curve.svg)
+///
+/// The curve is scaled to fit `(width, height)` with half a step of margin, and drawn as
+/// `stroke.len()` consecutive paths, so a single stroke draws it as one path and several
+/// strokes make a stepped gradient along the curve.
+///
+/// `corner_radius` rounds every turn of the curve, `0.0` keeps the corners sharp.
+///
+/// ```
+/// use geopattern::{space_filling_curve, SpaceFillingCurve};
+///
+/// let c = space_filling_curve(
+///     SpaceFillingCurve::Hilbert,
+///     3,
+///     (200.0, 200.0),
+///     &[("#222", 1.0), ("#555", 1.0), ("#888", 1.0)],
+///     4.0,
+///     6.0,
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn space_filling_curve(
+    curve: SpaceFillingCurve,
+    order: usize,
+    (width, height): (f32, f32),
+    stroke: &[(&str, f32)],
+    stroke_width: f32,
+    corner_radius: f32,
+    background_color: &str,
+) -> Document {
+    debug_assert!(!stroke.is_empty());
+
+    let system = match curve {
+        SpaceFillingCurve::Hilbert => {
+            LSystem::new("X", &[('X', "+YF-XFX-FY+"), ('Y', "-XF+YFY+FX-")], 90.0)
+        }
+        SpaceFillingCurve::Peano => LSystem::new(
+            "X",
+            &[
+                ('X', "XFYFX+F+YFXFY-F-XFYFX"),
+                ('Y', "YFXFY-F-XFYFX+F+YFXFY"),
+            ],
+            90.0,
+        ),
+        SpaceFillingCurve::Moore => {
+            debug_assert!(order > 0);
+            LSystem::new(
+                "LFL+F+LFL",
+                &[('L', "-RF+LFL+FR-"), ('R', "+LF-RFR-FL+")],
+                90.0,
+            )
+        }
+        SpaceFillingCurve::Gosper => LSystem::new(
+            "A",
+            &[('A', "A-B--B+A++AA+B-"), ('B', "+A-AA--A-B++B+A")],
+            60.0,
+        ),
+    };
+
+    let iterations = match curve {
+        SpaceFillingCurve::Moore => order.saturating_sub(1),
+        _ => order,
+    };
+
+    let mut points = match system
+        .fitted_lines(iterations, (width, height))
+        .into_iter()
+        .next()
+    {
+        Some(points) => points,
+        None => return create_document((width, height), background_color),
+    };
+
+    if curve == SpaceFillingCurve::Moore {
+        points.push(points[0]);
+    }
+
+    let n = points.len();
+
+    // every interior point is entered at `a` and left at `b`, joined by a curve through the point
+    let mut corners = points
+        .iter()
+        .map(|p| (*p, *p))
+        .collect::<Vec<((f32, f32), (f32, f32))>>();
+
+    if corner_radius > 0.0 {
+        for i in 1..n - 1 {
+            let (p, q, r) = (points[i - 1], points[i], points[i + 1]);
+            let (l1, l2) = (
+                ((q.0 - p.0).powi(2) + (q.1 - p.1).powi(2)).sqrt(),
+                ((r.0 - q.0).powi(2) + (r.1 - q.1).powi(2)).sqrt(),
+            );
+            let radius = corner_radius.min(l1 / 2.0).min(l2 / 2.0);
+
+            corners[i] = (
+                (
+                    q.0 - (q.0 - p.0) / l1 * radius,
+                    q.1 - (q.1 - p.1) / l1 * radius,
+                ),
+                (
+                    q.0 + (r.0 - q.0) / l2 * radius,
+                    q.1 + (r.1 - q.1) / l2 * radius,
+                ),
+            );
+        }
+    }
+
+    let mut doc = create_document((width, height), background_color);
+
+    let segments = n - 1;
+
+    for (i, (stroke_color, stroke_opacity)) in stroke.iter().enumerate() {
+        let (start, end) = (
+            i * segments / stroke.len(),
+            (i + 1) * segments / stroke.len(),
+        );
+
+        if start == end {
+            continue;
+        }
+
+        let mut path = format!("M {} {}", corners[start].1 .0, corners[start].1 .1);
+
+        for j in start + 1..=end {
+            let (a, b) = corners[j];
+            let p = points[j];
+
+            path.push_str(&format!(" L {} {}", a.0, a.1));
+
+            if j < n - 1 && corner_radius > 0.0 {
+                path.push_str(&format!(" Q {} {} {} {}", p.0, p.1, b.0, b.1));
+            }
+        }
+
+        doc = doc.add(
+            Path::new()
+                .set("d", path)
+                .set("fill", "none")
+                .set("stroke", *stroke_color)
+                .set("stroke-opacity", *stroke_opacity)
+                .set("stroke-width", stroke_width)
+                .set("stroke-linecap", "round")
+                .set("stroke-linejoin", "round"),
+        );
+    }
+
+    doc
+}
+
+/// squares
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/squares.svg)
+///
+/// ```
+/// use geopattern::squares;
+///
+/// let c = squares(
+///     60.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#ddd", 0.2),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn squares(
+    side: f32,
+    (width, height): (usize, usize),
+    fill: &[(&str, f32)],
+    stroke: (&str, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(fill.len(), width * height);
+
+    let mut doc = create_document(
+        (side * width as f32, side * height as f32),
+        background_color,
+    );
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            doc = doc.add(
+                Rectangle::new()
+                    .set("x", (x as f32) * side)
+                    .set("y", (y as f32) * side)
+                    .set("width", side)
+                    .set("height", side)
+                    .set("fill", fill[ix].0)
+                    .set("fill-opacity", fill[ix].1)
+                    .set("stroke", stroke.0)
+                    .set("stroke-opacity", stroke.1),
+            );
+        }
+    }
+
+    doc
+}
+
+/// Tartan
+///
+/// https://en.wikipedia.org/wiki/Tartan
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/tartan.svg)
+///
+/// `sett` lists the stripes as `(color, thread_count)`, and is used for both the warp and the
+/// weft, which are woven in a 2/2 twill with each thread `unit` wide. A `symmetric` sett is
+/// reflected about its first and last stripes, otherwise it repeats as given.
+///
+/// The tile covers a whole number of repeats of both the sett and the twill.
+///
+/// ```
+/// use geopattern::tartan;
+///
+/// let c = tartan(
+///     2.0,
+///     &[("#101010", 4), ("#c8102e", 24), ("#101010", 24), ("#f1be48", 4)],
+///     true,
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn tartan(unit: f32, sett: &[(&str, usize)], symmetric: bool) -> Document {
+    debug_assert!(!sett.is_empty());
+
+    let mut stripes = sett.to_vec();
+
+    if symmetric && sett.len() > 2 {
+        stripes.extend(sett[1..sett.len() - 1].iter().rev());
+    }
+
+    let colors = stripes.iter().fold(Vec::new(), |mut colors, (color, _)| {
+        if !colors.contains(color) {
+            colors.push(*color);
+        }
+
+        colors
+    });
+
+    let threads = stripes
+        .iter()
+        .flat_map(|(color, count)| {
+            let ix = colors.iter().position(|c| c == color).unwrap();
+            (0..*count).map(move |_| ix)
+        })
+        .collect::<Vec<usize>>();
+
+    let n = threads.len();
+    debug_assert!(n > 0);
+
+    // the smallest multiple of the sett that is also a multiple of the twill
+    let size = match n % 4 {
+        0 => n,
+        2 => n * 2,
+        _ => n * 4,
+    };
+
+    // the first colour fills in behind any seams between the stripes
+    let mut doc = create_document((unit * size as f32, unit * size as f32), sett[0].0);
+
+    for (ix, color) in colors.iter().enumerate() {
+        let path = cell_path(unit, (size, size), |x, y| {
+            // the warp shows where it passes over the weft
+            let thread = if (x + y) % 4 < 2 { x } else { y };
+            threads[thread % n] == ix
+        });
+
+        if !path.is_empty() {
+            doc = doc.add(Path::new().set("d", path).set("fill", *color));
+        }
+    }
+
+    doc
+}
+
+/// parses a thread count like `"K4 R24 K24 Y4"` into the stripes of a `tartan` sett, looking up
+/// the colour of each code in `colors`
+///
+/// Pivots marked with a `/`, as in `"K/4 R24 K24 Y/4"`, are read as plain counts.
+///
+/// Returns `None` when a code is not in `colors` or a count is not a number.
+///
+/// ```
+/// use geopattern::{tartan, tartan_sett};
+///
+/// let colors = [("K", "#101010"), ("R", "#c8102e"), ("Y", "#f1be48")];
+/// let sett = tartan_sett("K4 R24 K24 Y4", &colors).unwrap();
+///
+/// assert_eq!(sett, vec![("#101010", 4), ("#c8102e", 24), ("#101010", 24), ("#f1be48", 4)]);
+/// assert_eq!(tartan_sett("K4 Q24", &colors), None);
+/// assert_eq!(tartan_sett("K4 R2x", &colors), None);
+///
+/// println!("{}", tartan(2.0, &sett, true));
+/// ```
+pub fn tartan_sett<'a>(sett: &str, colors: &[(&str, &'a str)]) -> Option<Vec<(&'a str, usize)>> {
+    sett.split_whitespace()
+        .map(|stripe| {
+            let split = stripe
+                .find(|c: char| c == '/' || c.is_ascii_digit())
+                .unwrap_or(stripe.len());
+            let (code, count) = stripe.split_at(split);
+
+            let color = colors
+                .iter()
+                .find(|(c, _)| *c == code)
+                .map(|(_, color)| *color)?;
+
+            let count = count.trim_start_matches('/').parse().ok()?;
+
+            Some((color, count))
+        })
+        .collect()
+}
+
+/// tesselation
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/tesselation.svg)
+///
+/// ```
+/// use geopattern::tesselation;
+///
+/// let c = tesselation(
+///     60.0,
+///     &(0..20)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#ddd", 0.2),
 ///     "#987987",
 /// );
 ///
@@ -1825,9 +5207,9 @@ pub fn tesselation(
     debug_assert_eq!(fill.len(), 20);
 
     let hex_width = length * 2.0;
-    let hex_height = length * (3.0 as f32).sqrt();
+    let hex_height = length * 3.0_f32.sqrt();
 
-    let tess_height = length / 2.0 * (3.0 as f32).sqrt();
+    let tess_height = length / 2.0 * 3.0_f32.sqrt();
     let points = format!("0,0,{},{},0,{},0,0", tess_height, length / 2.0, length);
 
     let tile_width = length * 3.0 + tess_height * 2.0;
@@ -2175,62 +5557,379 @@ pub fn tesselation(
 ///
 /// https://generativeartistry.com/tutorials/tiled-lines/
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/tiled_lines.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/tiled_lines.svg)
+///
+/// Each cell has a line from its top left to its bottom right corner when `ltr` is set, and from
+/// its top right to its bottom left corner otherwise. See `tiled_lines_with` for other directions
+/// and more lines in each cell.
+///
+/// ```
+/// use geopattern::tiled_lines;
+///
+/// let c = tiled_lines(
+///     80.0,
+///     (2, 2),
+///     &(0..4).map(|i| i & 1 == 0).collect::<Vec<bool>>(),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     5.0,
+///     "#FFFFFF",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn tiled_lines(
+    step_size: f32,
+    (width, height): (usize, usize),
+    ltr: &[bool],
+    stroke: &[(&str, f32)],
+    stroke_width: f32,
+    background_color: &str,
+) -> Document {
+    tiled_lines_with(
+        step_size,
+        (width, height),
+        &ltr.iter()
+            .map(|ltr| {
+                if *ltr {
+                    TiledLinesDirection::Diagonal
+                } else {
+                    TiledLinesDirection::AntiDiagonal
+                }
+            })
+            .collect::<Vec<TiledLinesDirection>>(),
+        1,
+        stroke,
+        stroke_width,
+        background_color,
+    )
+}
+
+/// enum to identify the direction of the lines in a cell of `tiled_lines_with`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TiledLinesDirection {
+    /// from the top left to the bottom right
+    Diagonal,
+    /// from the top right to the bottom left
+    AntiDiagonal,
+    /// from left to right
+    Horizontal,
+    /// from top to bottom
+    Vertical,
+}
+
+/// tiled lines, in any of four directions and with parallel lines in each cell
+///
+/// https://generativeartistry.com/tutorials/tiled-lines/
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/tiled_lines_with.svg)
+///
+/// `Horizontal` and `Vertical` cells have `lines` parallel lines, `step_size / lines` apart and
+/// centered in their strips. `Diagonal` and `AntiDiagonal` cells have `2 * lines - 1` parallel
+/// segments, one through the corners and the rest `step_size / lines` apart along each edge, so
+/// they meet the diagonals of the neighbouring cells. Choosing only `Horizontal` and `Vertical`
+/// gives the variant from the end of the tutorial.
+///
+/// ```
+/// use geopattern::{tiled_lines_with, TiledLinesDirection};
+///
+/// let c = tiled_lines_with(
+///     40.0,
+///     (2, 2),
+///     &[
+///         TiledLinesDirection::Horizontal,
+///         TiledLinesDirection::Vertical,
+///         TiledLinesDirection::Diagonal,
+///         TiledLinesDirection::AntiDiagonal,
+///     ],
+///     3,
+///     &(0..4).map(|_| ("#222", 1.0)).collect::<Vec<(&str, f32)>>(),
+///     2.0,
+///     "#FFFFFF",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn tiled_lines_with(
+    step_size: f32,
+    (width, height): (usize, usize),
+    directions: &[TiledLinesDirection],
+    lines: usize,
+    stroke: &[(&str, f32)],
+    stroke_width: f32,
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(directions.len(), width * height);
+    debug_assert_eq!(directions.len(), stroke.len());
+
+    let mut doc = create_document(
+        (step_size * width as f32, step_size * height as f32),
+        background_color,
+    );
+
+    let lines = lines.max(1);
+    let spacing = step_size / lines as f32;
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            let (x, y) = (x as f32 * step_size, y as f32 * step_size);
+
+            let segments = match directions[ix] {
+                TiledLinesDirection::Horizontal | TiledLinesDirection::Vertical => (0..lines)
+                    .map(|k| {
+                        let offset = (k as f32 + 0.5) * spacing;
+
+                        match directions[ix] {
+                            TiledLinesDirection::Horizontal => {
+                                ((x, y + offset), (x + step_size, y + offset))
+                            }
+                            _ => ((x + offset, y), (x + offset, y + step_size)),
+                        }
+                    })
+                    .collect::<Vec<((f32, f32), (f32, f32))>>(),
+                _ => (1 - lines as isize..lines as isize)
+                    .map(|k| {
+                        let offset = k as f32 * spacing;
+
+                        // along the diagonal from the top left corner, moved down by `offset`
+                        let ((x0, y0), (x1, y1)) = if offset >= 0.0 {
+                            ((0.0, offset), (step_size - offset, step_size))
+                        } else {
+                            ((-offset, 0.0), (step_size, step_size + offset))
+                        };
+
+                        match directions[ix] {
+                            TiledLinesDirection::Diagonal => ((x + x0, y + y0), (x + x1, y + y1)),
+                            _ => ((x + step_size - x0, y + y0), (x + step_size - x1, y + y1)),
+                        }
+                    })
+                    .collect(),
+            };
+
+            let path = segments
+                .iter()
+                .map(|((x0, y0), (x1, y1))| format!("M {} {} L {} {}", x0, y0, x1, y1))
+                .collect::<Vec<String>>()
+                .join(" ");
+
+            doc = doc.add(
+                Path::new()
+                    .set("d", path)
+                    .set("fill", "none")
+                    .set("stroke", stroke[ix].0)
+                    .set("stroke-opacity", stroke[ix].1)
+                    .set("stroke-width", stroke_width)
+                    .set("stroke-linecap", "square"),
+            );
+        }
+    }
+
+    doc
+}
+
+/// triangles
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/triangles.svg)
+///
+/// ```
+/// use geopattern::triangles;
+///
+/// let c = triangles(
+///     60.0,
+///     (2, 2),
+///     &(0..4)
+///         .map(|v| {
+///             (
+///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 0.02 + (v as f32) / 4.0,
+///             )
+///         })
+///         .collect::<Vec<(&str, f32)>>(),
+///     ("#ddd", 0.2),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn triangles(
+    side: f32,
+    (width, height): (usize, usize),
+    fill: &[(&str, f32)],
+    stroke: (&str, f32),
+    background_color: &str,
+) -> Document {
+    debug_assert_eq!(fill.len(), width * height);
+
+    let triangle_height = 3.0_f32.sqrt() * side / 2.0;
+    let points = format!(
+        "{},0,{},{},0,{},{},0",
+        side / 2.0,
+        side,
+        triangle_height,
+        triangle_height,
+        side / 2.0
+    );
+
+    let mut doc = create_document(
+        (side / 2.0 * width as f32, triangle_height * height as f32),
+        background_color,
+    );
+
+    for y in 0..height {
+        for x in 0..width {
+            let ix = y * width + x;
+
+            let rot = match (x, y) {
+                (x, y) if y % 2 == 0 && x % 2 == 0 => 180,
+                (x, y) if y % 2 != 0 && x % 2 != 0 => 180,
+                _ => 0,
+            };
+
+            let p = Polyline::new()
+                .set("points", points.as_str())
+                .set("fill", fill[ix].0)
+                .set("fill-opacity", fill[ix].1)
+                .set("stroke", stroke.0)
+                .set("stroke-opacity", stroke.1);
+
+            doc = doc.add(p.clone().set(
+                "transform",
+                format!(
+                    "translate({}, {}) rotate({}, {}, {})",
+                    (x as f32) * side * 0.5 - side / 2.0,
+                    triangle_height * (y as f32),
+                    rot,
+                    side / 2.0,
+                    triangle_height / 2.0
+                ),
+            ));
+
+            if x == 0 {
+                doc = doc.add(p.set(
+                    "transform",
+                    format!(
+                        "translate({}, {}) rotate({}, {}, {})",
+                        width as f32 * side * 0.5 - side / 2.0,
+                        triangle_height * (y as f32),
+                        rot,
+                        side / 2.0,
+                        triangle_height / 2.0
+                    ),
+                ));
+            }
+        }
+    }
+
+    doc
+}
+
+/// Triangular Mesh
+///
+/// https://generativeartistry.com/tutorials/triangular-mesh/
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/triangular_mesh.svg)
+///
+/// `fill` has required size `(4 * width - 2) * height`
+///
+/// `entropy` has required size `width * (height + 1)`
 ///
 /// ```
-/// use geopattern::tiled_lines;
+/// use geopattern::triangular_mesh;
 ///
-/// let c = tiled_lines(
-///     80,
+/// let c = triangular_mesh(
+///     60.0,
 ///     (2, 2),
-///     &(0..4).map(|i| i & 1 == 0).collect::<Vec<bool>>(),
-///     &(0..4)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     5.0,
-///     "#FFFFFF",
+///     &(0..6).map(|v| (0.02 + (v as f32) / 4.0, 0.02 + (v as f32) / 4.0)).collect::<Vec<(f32, f32)>>(),
+///     &(0..12).map(|v| ("#ddd", 0.2)).collect::<Vec<(&str, f32)>>(),
+///     (1.0, "#ddd", 0.2),
+///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn tiled_lines(
-    step_size: usize,
+pub fn triangular_mesh(
+    side: f32,
     (width, height): (usize, usize),
-    ltr: &[bool],
-    stroke: &[(&str, f32)],
-    stroke_width: f32,
+    entropy: &[(f32, f32)],
+    fill: &[(&str, f32)],
+    stroke: (f32, &str, f32),
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(ltr.len(), width * height);
-    debug_assert_eq!(ltr.len(), stroke.len());
+    debug_assert_eq!(fill.len(), (4 * width - 2) * height);
+    debug_assert_eq!(entropy.len(), width * (height + 1));
 
-    let mut doc = create_document((step_size * width, step_size * height), background_color);
+    let mut doc = create_document(
+        (side * width as f32, side * (height + 1) as f32),
+        background_color,
+    );
 
-    for x in 0..width {
-        for y in 0..height {
-            let ix = x * height + y;
+    let mut lines = Vec::new();
+
+    for y in 0..height {
+        let mut line = Vec::new();
+
+        for x in 0..2 * width {
+            let xx = x / 2;
+
+            let e1 = entropy[y * width + xx];
+            let e2 = entropy[(y + 1) * width + xx];
 
-            let (x, y) = (x * step_size, y * step_size);
+            if y & 1 == 0 {
+                line.push((
+                    side / 4.0 + xx as f32 * side + e2.0,
+                    side / 2.0 + (y + 1) as f32 * side + e2.1,
+                ));
 
-            let path = if ltr[ix] {
-                format!("M {} {} L {} {}", x, y, x + step_size, y + step_size)
+                line.push((
+                    side / 4.0 + side / 2.0 + xx as f32 * side + e1.0,
+                    side / 2.0 + y as f32 * side + e1.1,
+                ));
             } else {
-                format!("M {} {} L {} {}", x + step_size, y, x, y + step_size)
-            };
+                line.push((
+                    side / 4.0 + xx as f32 * side + e1.0,
+                    side / 2.0 + y as f32 * side + e1.1,
+                ));
+
+                line.push((
+                    side / 4.0 + side / 2.0 + xx as f32 * side + e2.0,
+                    side / 2.0 + (y + 1) as f32 * side + e2.1,
+                ));
+            }
+        }
+
+        lines.push(line);
+    }
 
+    for (j, line) in lines.iter().enumerate() {
+        for i in 0..line.len() - 2 {
             doc = doc.add(
                 Path::new()
-                    .set("d", path)
-                    .set("fill", "none")
-                    .set("stroke", stroke[ix].0)
-                    .set("stroke-opacity", stroke[ix].1)
-                    .set("stroke-width", stroke_width)
-                    .set("stroke-linecap", "square"),
+                    .set(
+                        "d",
+                        format!(
+                            "M {} {} L {} {} L {} {} Z",
+                            line[i].0,
+                            line[i].1,
+                            line[i + 1].0,
+                            line[i + 1].1,
+                            line[i + 2].0,
+                            line[i + 2].1
+                        ),
+                    )
+                    .set("fill", fill[j * (line.len() - 2) + i].0)
+                    .set("fill-opacity", fill[j * (line.len() - 2) + i].1)
+                    .set("stroke", stroke.1)
+                    .set("stroke-width", stroke.0)
+                    .set("stroke-opacity", stroke.2)
+                    .set("stroke-linejoin", "bevel"),
             );
         }
     }
@@ -2238,358 +5937,909 @@ pub fn tiled_lines(
     doc
 }
 
-/// triangles
+/// Un Deus Trois
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/triangles.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/un_deus_trois.svg)
+///
+/// See `un_deus_trois_with` for other numbers of lines and seeded rotations.
 ///
 /// ```
-/// use geopattern::triangles;
+/// use geopattern::un_deus_trois;
 ///
-/// let c = triangles(
+/// let c = un_deus_trois(
 ///     60.0,
 ///     (2, 2),
-///     &(0..4)
+///     &(0..12)
 ///         .map(|v| {
 ///             (
 ///                 if v & 1 == 0 { "#222" } else { "#ddd" },
+///                 2.0,
 ///                 0.02 + (v as f32) / 4.0,
 ///             )
 ///         })
-///         .collect::<Vec<(&str, f32)>>(),
-///     ("#ddd", 0.2),
+///         .collect::<Vec<(&str, f32, f32)>>(),
+///     &(0..12)
+///         .map(|v| 30.0 * v as f32)
+///         .collect::<Vec<f32>>(),
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn triangles(
-    side: f32,
+pub fn un_deus_trois(
+    step_size: f32,
     (width, height): (usize, usize),
-    fill: &[(&str, f32)],
-    stroke: (&str, f32),
+    stroke: &[(&str, f32, f32)],
+    rotation: &[f32],
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(fill.len(), width * height);
+    debug_assert_eq!(rotation.len(), 3 * width * height);
+    debug_assert_eq!(stroke.len(), 3 * width * height);
 
-    let triangle_height = (3.0 as f32).sqrt() * side / 2.0;
-    let points = format!(
-        "{},0,{},{},0,{},{},0",
-        side / 2.0,
-        side,
-        triangle_height,
-        triangle_height,
-        side / 2.0
-    );
+    un_deus_trois_with(
+        step_size,
+        (width, height),
+        &[1, 2, 3],
+        &UnDeusTroisRotation::Explicit(rotation),
+        stroke,
+        false,
+        background_color,
+    )
+}
+
+/// enum to identify how `un_deus_trois_with` rotates the lines in each cell
+#[derive(Clone, Copy, Debug)]
+pub enum UnDeusTroisRotation<'a> {
+    /// rotations in degrees, one for each cell
+    Explicit(&'a [f32]),
+    /// `(seed, (min, max))`, rotations in degrees chosen uniformly between `min` and `max`
+    Uniform(u64, (f32, f32)),
+    /// `(seed, angles)`, rotations in degrees chosen from `angles`
+    Choice(u64, &'a [f32]),
+}
+
+/// Un Deus Trois, with any number of bands and lines
+///
+/// https://generativeartistry.com/tutorials/un-deux-trois/
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/un_deus_trois_with.svg)
+///
+/// The pattern has a band of `height` rows for each of `lines`, and every cell in band `i` has
+/// `lines[i]` lines. One line is at the center of the cell, two are at 0.2 and 0.8 of its
+/// width, and three or more are spread evenly from 0.1 to 0.9. `un_deus_trois` has the bands of
+/// the original artwork, `&[1, 2, 3]`.
+///
+/// With `round_caps`, the lines end in semicircles that stop at the edges of the cell.
+///
+/// ```
+/// use geopattern::{un_deus_trois_with, UnDeusTroisRotation};
+///
+/// let c = un_deus_trois_with(
+///     60.0,
+///     (2, 1),
+///     &[4, 1],
+///     &UnDeusTroisRotation::Choice(7, &[0.0, 45.0, 90.0, 135.0]),
+///     &(0..4).map(|_| ("#222", 6.0, 0.8)).collect::<Vec<(&str, f32, f32)>>(),
+///     true,
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn un_deus_trois_with(
+    step_size: f32,
+    (width, height): (usize, usize),
+    lines: &[usize],
+    rotation: &UnDeusTroisRotation,
+    stroke: &[(&str, f32, f32)],
+    round_caps: bool,
+    background_color: &str,
+) -> Document {
+    let cells = lines.len() * width * height;
+
+    debug_assert_eq!(stroke.len(), cells);
+
+    let rotation = match *rotation {
+        UnDeusTroisRotation::Explicit(rotation) => rotation.to_vec(),
+        UnDeusTroisRotation::Uniform(seed, (min, max)) => {
+            let mut rng = Rng::new(seed);
+            (0..cells)
+                .map(|_| min + (max - min) * rng.next_f32())
+                .collect()
+        }
+        UnDeusTroisRotation::Choice(seed, angles) => {
+            debug_assert!(!angles.is_empty());
+
+            let mut rng = Rng::new(seed);
+            (0..cells)
+                .map(|_| angles[rng.below(angles.len())])
+                .collect()
+        }
+    };
+
+    debug_assert_eq!(rotation.len(), cells);
 
     let mut doc = create_document(
-        (side / 2.0 * width as f32, triangle_height * height as f32),
+        (
+            step_size * width as f32,
+            step_size * (height * lines.len()) as f32,
+        ),
         background_color,
     );
 
-    for y in 0..height {
-        for x in 0..width {
-            let ix = y * width + x;
+    for (band, n) in lines.iter().enumerate() {
+        for y in band * height..(band + 1) * height {
+            for x in 0..width {
+                let ix = y * width + x;
+                let (stroke, stroke_width, stroke_opacity) = stroke[ix];
 
-            let rot = match (x, y) {
-                (x, y) if y % 2 == 0 && x % 2 == 0 => 180,
-                (x, y) if y % 2 != 0 && x % 2 != 0 => 180,
-                _ => 0,
-            };
+                let inset = if round_caps {
+                    (stroke_width / 2.0).min(step_size / 2.0)
+                } else {
+                    0.0
+                };
+
+                let data = (0..*n)
+                    .map(|i| {
+                        let offset = match (n, i) {
+                            (1, _) => 0.5,
+                            (2, 0) => 0.2,
+                            (2, _) => 0.8,
+                            _ => (1.0 + 8.0 * i as f32 / (n - 1) as f32) / 10.0,
+                        };
+
+                        format!(
+                            "M {} {} L {} {}",
+                            (x as f32 + offset) * step_size,
+                            y as f32 * step_size + inset,
+                            (x as f32 + offset) * step_size,
+                            (y + 1) as f32 * step_size - inset
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ");
+
+                let mut path = Path::new()
+                    .set("d", data)
+                    .set("stroke", stroke)
+                    .set("stroke-width", stroke_width)
+                    .set("stroke-opacity", stroke_opacity)
+                    .set(
+                        "transform",
+                        format!(
+                            "rotate({} {} {})",
+                            rotation[ix],
+                            (x as f32 + 0.5) * step_size,
+                            (y as f32 + 0.5) * step_size
+                        ),
+                    );
+
+                if round_caps {
+                    path = path.set("stroke-linecap", "round");
+                }
+
+                doc = doc.add(path);
+            }
+        }
+    }
+
+    doc
+}
+
+/// enum to identify the tiling drawn by `uniform_tiling`
+///
+/// The first eleven are the Archimedean tilings, documented with their vertex configuration,
+/// the rest are their Laves duals. The duals of the triangular and hexagonal tilings are each
+/// other and the square tiling is its own dual.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UniformTiling {
+    /// 3.3.3.3.3.3
+    Triangular,
+    /// 4.4.4.4
+    Square,
+    /// 6.6.6
+    Hexagonal,
+    /// 3.6.3.6
+    Trihexagonal,
+    /// 3.4.6.4
+    Rhombitrihexagonal,
+    /// 3.12.12
+    TruncatedHexagonal,
+    /// 4.6.12
+    TruncatedTrihexagonal,
+    /// 4.8.8
+    TruncatedSquare,
+    /// 3.3.4.3.4
+    SnubSquare,
+    /// 3.3.3.3.6
+    SnubHexagonal,
+    /// 3.3.3.4.4
+    ElongatedTriangular,
+    /// dual of 4.8.8
+    TetrakisSquare,
+    /// dual of 3.12.12
+    TriakisTriangular,
+    /// dual of 3.6.3.6
+    Rhombille,
+    /// dual of 3.4.6.4
+    DeltoidalTrihexagonal,
+    /// dual of 4.6.12
+    Kisrhombille,
+    /// dual of 3.3.4.3.4
+    CairoPentagonal,
+    /// dual of 3.3.3.3.6
+    FloretPentagonal,
+    /// dual of 3.3.3.4.4
+    PrismaticPentagonal,
+}
+
+impl UniformTiling {
+    /// number of distinct face types, which is the required size of `fill` in `uniform_tiling`
+    pub fn face_types(self) -> usize {
+        let (_, faces) = uniform_tiling_faces(self);
+        faces.iter().map(|(t, _)| t + 1).max().unwrap_or(0)
+    }
+}
+
+const TILING_EPSILON: f32 = 1e-3;
+
+fn regular_polygon((cx, cy): (f32, f32), n: usize, rotation: f32) -> Vec<(f32, f32)> {
+    let r = 0.5 / (std::f32::consts::PI / n as f32).sin();
+
+    (0..n)
+        .map(|k| {
+            let a = rotation + 2.0 * std::f32::consts::PI * k as f32 / n as f32;
+            (cx + r * a.cos(), cy + r * a.sin())
+        })
+        .collect()
+}
+
+fn polygon_points(points: &[(f32, f32)]) -> String {
+    points
+        .iter()
+        .chain(points.first())
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// regular polygons `((cx, cy), sides, rotation)` with unit edges, whose centers lie in a
+/// rectangular cell that repeats seamlessly
+#[allow(clippy::type_complexity)]
+fn archimedean_cell(tiling: UniformTiling) -> ((f32, f32), Vec<((f32, f32), usize, f32)>) {
+    let s3 = 3.0_f32.sqrt();
+    let h = s3 / 2.0;
+    let (r3, rr3) = (s3 / 6.0, s3 / 3.0);
+
+    let on = |d: f32, a: f32| (d * a.to_radians().cos(), d * a.to_radians().sin());
+
+    let ((cell_width, cell_height), rotation, offsets, motif): (
+        _,
+        f32,
+        Vec<(f32, f32)>,
+        Vec<(f32, f32, usize, f32)>,
+    ) = match tiling {
+        UniformTiling::Triangular => (
+            (1.0, s3),
+            0.0,
+            vec![(0.0, 0.0)],
+            vec![
+                (0.5, rr3, 3, -90.0),
+                (0.0, r3, 3, 90.0),
+                (0.0, h + rr3, 3, -90.0),
+                (0.5, h + r3, 3, 90.0),
+            ],
+        ),
+        UniformTiling::Square => ((1.0, 1.0), 0.0, vec![(0.0, 0.0)], vec![(0.5, 0.5, 4, 45.0)]),
+        UniformTiling::Hexagonal => (
+            (3.0, s3),
+            0.0,
+            vec![(0.0, 0.0), (1.5, h)],
+            vec![(0.0, 0.0, 6, 0.0)],
+        ),
+        UniformTiling::Trihexagonal => (
+            (2.0, 2.0 * s3),
+            0.0,
+            vec![(0.0, 0.0), (1.0, s3)],
+            vec![
+                (0.0, 0.0, 6, 0.0),
+                (1.0, rr3, 3, -90.0),
+                (0.0, 2.0 * rr3, 3, 90.0),
+            ],
+        ),
+        UniformTiling::Rhombitrihexagonal => {
+            let d = 1.0 + s3;
+            let mut motif = vec![(0.0, 0.0, 6, 0.0)];
+            for a in [90.0, 30.0, -30.0].iter() {
+                let (x, y) = on(d / 2.0, *a);
+                motif.push((x, y, 4, a + 45.0));
+            }
+            for a in [0.0, 60.0].iter() {
+                let (x, y) = on(d / s3, *a);
+                motif.push((x, y, 3, a + 180.0));
+            }
+            ((d * s3, d), 0.0, vec![(0.0, 0.0), (d * h, d / 2.0)], motif)
+        }
+        UniformTiling::TruncatedHexagonal => {
+            let d = 2.0 + s3;
+            let mut motif = vec![(0.0, 0.0, 12, 15.0)];
+            for a in [30.0, 90.0].iter() {
+                let (x, y) = on(d / s3, *a);
+                motif.push((x, y, 3, *a));
+            }
+            ((d, d * s3), 0.0, vec![(0.0, 0.0), (d / 2.0, d * h)], motif)
+        }
+        UniformTiling::TruncatedTrihexagonal => {
+            let d = 3.0 + s3;
+            let mut motif = vec![(0.0, 0.0, 12, 15.0)];
+            for a in [0.0, 60.0, 120.0].iter() {
+                let (x, y) = on(d / 2.0, *a);
+                motif.push((x, y, 4, a + 45.0));
+            }
+            for a in [30.0, 90.0].iter() {
+                let (x, y) = on(d / s3, *a);
+                motif.push((x, y, 6, 0.0));
+            }
+            ((d, d * s3), 0.0, vec![(0.0, 0.0), (d / 2.0, d * h)], motif)
+        }
+        UniformTiling::TruncatedSquare => {
+            let d = 1.0 + 2.0_f32.sqrt();
+            (
+                (d, d),
+                0.0,
+                vec![(0.0, 0.0)],
+                vec![(0.0, 0.0, 8, 22.5), (d / 2.0, d / 2.0, 4, 0.0)],
+            )
+        }
+        UniformTiling::SnubSquare => {
+            let a = (2.0 + s3).sqrt();
+            let mut motif = vec![(0.0, 0.0, 4, 60.0), (a / 2.0, a / 2.0, 4, 30.0)];
+            for k in 0..4 {
+                let t = 15.0 + 90.0 * k as f32;
+                let (x, y) = on(0.5 + r3, t);
+                motif.push((x, y, 3, t));
+
+                let t = 75.0 + 90.0 * k as f32;
+                let (x, y) = on(0.5 + r3, t);
+                motif.push((a / 2.0 + x, a / 2.0 + y, 3, t));
+            }
+            ((a, a), 0.0, vec![(0.0, 0.0)], motif)
+        }
+        UniformTiling::SnubHexagonal => {
+            let mut motif = vec![(0.0, 0.0, 6, 0.0)];
+            for k in 0..6 {
+                let t = 30.0 + 60.0 * k as f32;
+                let (x, y) = on(h + r3, t);
+                motif.push((x, y, 3, t));
+            }
+            motif.push((1.0, 2.0 / s3, 3, 90.0));
+            motif.push((-0.5, 2.5 / s3, 3, 30.0));
+            (
+                (7.0_f32.sqrt(), 21.0_f32.sqrt()),
+                -h.atan2(2.5).to_degrees(),
+                vec![(0.0, 0.0), (0.5, 3.0 * h)],
+                motif,
+            )
+        }
+        UniformTiling::ElongatedTriangular => (
+            (1.0, 2.0 + s3),
+            0.0,
+            vec![(0.0, 0.0)],
+            vec![
+                (0.5, 0.5, 4, 45.0),
+                (0.5, 1.0 + r3, 3, 90.0),
+                (0.0, 1.0 + rr3, 3, -90.0),
+                (0.0, 1.5 + h, 4, 45.0),
+                (0.0, 2.0 + h + r3, 3, 90.0),
+                (0.5, 2.0 + h + rr3, 3, -90.0),
+            ],
+        ),
+        _ => unreachable!("laves tilings are derived from their archimedean duals"),
+    };
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let wrap = |v: f32, m: f32| {
+        let v = v.rem_euclid(m);
+        if m - v < TILING_EPSILON {
+            0.0
+        } else {
+            v
+        }
+    };
+
+    let mut items: Vec<((f32, f32), usize, f32)> = Vec::new();
+
+    for (ox, oy) in offsets.iter() {
+        for (cx, cy, n, r) in motif.iter() {
+            let (x, y) = (cx + ox, cy + oy);
+            let (x, y) = (
+                wrap(x * cos - y * sin, cell_width),
+                wrap(x * sin + y * cos, cell_height),
+            );
+
+            let exists = items.iter().any(|((px, py), pn, _)| {
+                pn == n && (px - x).abs() < TILING_EPSILON && (py - y).abs() < TILING_EPSILON
+            });
+
+            if !exists {
+                items.push(((x, y), *n, (r + rotation).to_radians()));
+            }
+        }
+    }
+
+    ((cell_width, cell_height), items)
+}
+
+/// faces `(face_type, points)` with unit edges whose reference point lies in a rectangular
+/// cell that repeats seamlessly
+#[allow(clippy::type_complexity)]
+fn uniform_tiling_faces(tiling: UniformTiling) -> ((f32, f32), Vec<(usize, Vec<(f32, f32)>)>) {
+    let (primal, dual) = match tiling {
+        UniformTiling::TetrakisSquare => (UniformTiling::TruncatedSquare, true),
+        UniformTiling::TriakisTriangular => (UniformTiling::TruncatedHexagonal, true),
+        UniformTiling::Rhombille => (UniformTiling::Trihexagonal, true),
+        UniformTiling::DeltoidalTrihexagonal => (UniformTiling::Rhombitrihexagonal, true),
+        UniformTiling::Kisrhombille => (UniformTiling::TruncatedTrihexagonal, true),
+        UniformTiling::CairoPentagonal => (UniformTiling::SnubSquare, true),
+        UniformTiling::FloretPentagonal => (UniformTiling::SnubHexagonal, true),
+        UniformTiling::PrismaticPentagonal => (UniformTiling::ElongatedTriangular, true),
+        t => (t, false),
+    };
+
+    let ((w, h), items) = archimedean_cell(primal);
+
+    if !dual {
+        let mut sides = items.iter().map(|(_, n, _)| *n).collect::<Vec<usize>>();
+        sides.sort_unstable();
+        sides.dedup();
+
+        let faces = items
+            .iter()
+            .map(|(c, n, r)| {
+                (
+                    sides.iter().position(|s| s == n).unwrap(),
+                    regular_polygon(*c, *n, *r),
+                )
+            })
+            .collect();
+
+        return ((w, h), faces);
+    }
 
-            let p = Polyline::new()
-                .set("points", points.as_str())
-                .set("fill", fill[ix].0)
-                .set("fill-opacity", fill[ix].1)
-                .set("stroke", stroke.0)
-                .set("stroke-opacity", stroke.1);
+    let close = |(ax, ay): (f32, f32), (bx, by): (f32, f32)| {
+        (ax - bx).abs() < TILING_EPSILON && (ay - by).abs() < TILING_EPSILON
+    };
 
-            doc = doc.add(p.clone().set(
-                "transform",
-                format!(
-                    "translate({}, {}) rotate({}, {}, {})",
-                    (x as f32) * side * 0.5 - side / 2.0,
-                    triangle_height * (y as f32),
-                    rot,
-                    side / 2.0,
-                    triangle_height / 2.0
-                ),
-            ));
+    // the primal faces of the cell and its neighbours, so that every vertex in the cell
+    // sees all the faces around it
+    let mut neighbourhood = Vec::new();
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            for ((cx, cy), n, r) in items.iter() {
+                let c = (cx + dx as f32 * w, cy + dy as f32 * h);
+                neighbourhood.push((c, regular_polygon(c, *n, *r)));
+            }
+        }
+    }
 
-            if x == 0 {
-                doc = doc.add(p.set(
-                    "transform",
-                    format!(
-                        "translate({}, {}) rotate({}, {}, {})",
-                        width as f32 * side * 0.5 - side / 2.0,
-                        triangle_height * (y as f32),
-                        rot,
-                        side / 2.0,
-                        triangle_height / 2.0
-                    ),
-                ));
+    let mut vertices: Vec<(f32, f32)> = Vec::new();
+    for (_, points) in neighbourhood.iter() {
+        for &(x, y) in points.iter() {
+            let inside = x > -TILING_EPSILON
+                && x < w - TILING_EPSILON
+                && y > -TILING_EPSILON
+                && y < h - TILING_EPSILON;
+
+            if inside && !vertices.iter().any(|v| close(*v, (x, y))) {
+                vertices.push((x, y));
             }
         }
     }
 
-    doc
+    let mut shapes: Vec<Vec<(f32, f32)>> = Vec::new();
+    let mut faces = Vec::new();
+
+    for &(vx, vy) in vertices.iter() {
+        let mut points = neighbourhood
+            .iter()
+            .filter(|(_, points)| points.iter().any(|p| close(*p, (vx, vy))))
+            .map(|(c, _)| *c)
+            .collect::<Vec<(f32, f32)>>();
+
+        let angle = |(x, y): &(f32, f32)| (y - vy).atan2(x - vx);
+        points.sort_by(|a, b| angle(a).partial_cmp(&angle(b)).unwrap());
+
+        // laves faces are all congruent, so they are typed by orientation instead
+        let shape = points
+            .iter()
+            .map(|(x, y)| (x - vx, y - vy))
+            .collect::<Vec<(f32, f32)>>();
+
+        let t = match shapes.iter().position(|s| {
+            s.len() == shape.len() && s.iter().all(|p| shape.iter().any(|q| close(*p, *q)))
+        }) {
+            Some(t) => t,
+            None => {
+                shapes.push(shape);
+                shapes.len() - 1
+            }
+        };
+
+        faces.push((t, points));
+    }
+
+    ((w, h), faces)
 }
 
-/// Triangular Mesh
+/// uniform tiling
 ///
-/// https://generativeartistry.com/tutorials/triangular-mesh/
+/// https://en.wikipedia.org/wiki/Euclidean_tilings_by_convex_regular_polygons
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/triangular_mesh.svg)
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/uniform_tiling.svg)
 ///
-/// `fill` has required size `(4 * width - 2) * height`
+/// `side` is the edge length of the Archimedean tiling, Laves tilings are scaled by their dual.
 ///
-/// `entropy` has required size `width * (height + 1)`
+/// `fill` has required size `tiling.face_types()`. Faces of an Archimedean tiling are typed by
+/// their number of sides, smallest first, faces of a Laves tiling are typed by orientation.
+///
+/// `(width, height)` is the number of repetitions of the smallest rectangular cell of the tiling.
 ///
 /// ```
-/// use geopattern::triangular_mesh;
+/// use geopattern::{uniform_tiling, UniformTiling};
 ///
-/// let c = triangular_mesh(
-///     60.0,
+/// let c = uniform_tiling(
+///     UniformTiling::Rhombitrihexagonal,
+///     20.0,
 ///     (2, 2),
-///     &(0..6).map(|v| (0.02 + (v as f32) / 4.0, 0.02 + (v as f32) / 4.0)).collect::<Vec<(f32, f32)>>(),
-///     &(0..12).map(|v| ("#ddd", 0.2)).collect::<Vec<(&str, f32)>>(),
-///     (1.0, "#ddd", 0.2),
+///     &[("#222", 0.4), ("#ddd", 0.6), ("#987", 0.8)],
+///     ("#ddd", 0.2),
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn triangular_mesh(
+pub fn uniform_tiling(
+    tiling: UniformTiling,
     side: f32,
     (width, height): (usize, usize),
-    entropy: &[(f32, f32)],
     fill: &[(&str, f32)],
-    stroke: (f32, &str, f32),
+    stroke: (&str, f32),
     background_color: &str,
 ) -> Document {
-    debug_assert_eq!(fill.len(), (4 * width - 2) * height);
-    debug_assert_eq!(entropy.len(), width * (height + 1));
+    let ((cell_width, cell_height), faces) = uniform_tiling_faces(tiling);
 
-    let mut doc = create_document(
-        (side * width as f32, side * (height + 1) as f32),
-        background_color,
+    debug_assert_eq!(
+        fill.len(),
+        faces.iter().map(|(t, _)| t + 1).max().unwrap_or(0)
     );
 
-    let mut lines = Vec::new();
+    let (cell_width, cell_height) = (cell_width * side, cell_height * side);
+    let (tile_width, tile_height) = (cell_width * width as f32, cell_height * height as f32);
 
-    for y in 0..height {
-        let mut line = Vec::new();
+    let mut doc = create_document((tile_width, tile_height), background_color);
 
-        for x in 0..2 * width {
-            let xx = x / 2;
+    // one extra cell on each side, for the faces that wrap around the edges
+    for y in -1..=height as isize {
+        for x in -1..=width as isize {
+            let (dx, dy) = (x as f32 * cell_width, y as f32 * cell_height);
 
-            let e1 = entropy[y * width + xx];
-            let e2 = entropy[(y + 1) * width + xx];
+            for (t, points) in faces.iter() {
+                let points = points
+                    .iter()
+                    .map(|(px, py)| (px * side + dx, py * side + dy))
+                    .collect::<Vec<(f32, f32)>>();
 
-            if y & 1 == 0 {
-                line.push((
-                    side / 4.0 + xx as f32 * side + e2.0,
-                    side / 2.0 + (y + 1) as f32 * side + e2.1,
-                ));
+                let visible = points.iter().any(|p| p.0 > 0.0)
+                    && points.iter().any(|p| p.0 < tile_width)
+                    && points.iter().any(|p| p.1 > 0.0)
+                    && points.iter().any(|p| p.1 < tile_height);
 
-                line.push((
-                    side / 4.0 + side / 2.0 + xx as f32 * side + e1.0,
-                    side / 2.0 + y as f32 * side + e1.1,
-                ));
-            } else {
-                line.push((
-                    side / 4.0 + xx as f32 * side + e1.0,
-                    side / 2.0 + y as f32 * side + e1.1,
-                ));
+                if !visible {
+                    continue;
+                }
 
-                line.push((
-                    side / 4.0 + side / 2.0 + xx as f32 * side + e2.0,
-                    side / 2.0 + (y + 1) as f32 * side + e2.1,
-                ));
+                doc = doc.add(
+                    Polyline::new()
+                        .set("points", polygon_points(&points))
+                        .set("fill", fill[*t].0)
+                        .set("fill-opacity", fill[*t].1)
+                        .set("stroke", stroke.0)
+                        .set("stroke-opacity", stroke.1),
+                );
             }
         }
-
-        lines.push(line);
     }
 
-    for (j, line) in lines.iter().enumerate() {
-        for i in 0..line.len() - 2 {
-            doc = doc.add(
-                Path::new()
-                    .set(
-                        "d",
-                        format!(
-                            "M {} {} L {} {} L {} {} Z",
-                            line[i].0,
-                            line[i].1,
-                            line[i + 1].0,
-                            line[i + 1].1,
-                            line[i + 2].0,
-                            line[i + 2].1
-                        ),
-                    )
-                    .set("fill", fill[j * (line.len() - 2) + i].0)
-                    .set("fill-opacity", fill[j * (line.len() - 2) + i].1)
-                    .set("stroke", stroke.1)
-                    .set("stroke-width", stroke.0)
-                    .set("stroke-opacity", stroke.2)
-                    .set("stroke-linejoin", "bevel"),
-            );
+    doc
+}
+
+/// enum to identify one of the 17 wallpaper groups, in their crystallographic notation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WallpaperGroup {
+    /// translations only
+    P1,
+    /// 2-fold rotations
+    P2,
+    /// reflections
+    Pm,
+    /// glide reflections
+    Pg,
+    /// reflections and glide reflections
+    Cm,
+    /// reflections in two directions
+    Pmm,
+    /// reflections and 2-fold rotations off the mirrors
+    Pmg,
+    /// glide reflections in two directions
+    Pgg,
+    /// reflections in two directions, on a centered lattice
+    Cmm,
+    /// 4-fold rotations
+    P4,
+    /// 4-fold rotations and reflections through the rotation centers
+    P4m,
+    /// 4-fold rotations and reflections off the rotation centers
+    P4g,
+    /// 3-fold rotations
+    P3,
+    /// 3-fold rotations and reflections through every rotation center
+    P3m1,
+    /// 3-fold rotations and reflections through some rotation centers
+    P31m,
+    /// 6-fold rotations
+    P6,
+    /// 6-fold rotations and reflections
+    P6m,
+}
+
+/// symmetry operations `((m00, m01, m10, m11), (t0, t1))` of a wallpaper group in lattice
+/// coordinates, along with whether the rectangular cell is centered
+#[allow(clippy::type_complexity)]
+fn wallpaper_operations(group: WallpaperGroup) -> (Vec<((f32, f32, f32, f32), (f32, f32))>, bool) {
+    let identity = (1.0, 0.0, 0.0, 1.0);
+    let half_turn = (-1.0, 0.0, 0.0, -1.0);
+    let quarter_turns = [(0.0, -1.0, 1.0, 0.0), (0.0, 1.0, -1.0, 0.0)];
+    let mirrors = [(-1.0, 0.0, 0.0, 1.0), (1.0, 0.0, 0.0, -1.0)];
+    let diagonal_mirrors = [(0.0, 1.0, 1.0, 0.0), (0.0, -1.0, -1.0, 0.0)];
+
+    let third_turns = [(0.0, -1.0, 1.0, -1.0), (-1.0, 1.0, -1.0, 0.0)];
+    let sixth_turns = [(0.0, 1.0, -1.0, 1.0), (1.0, -1.0, 1.0, 0.0)];
+    let m1_mirrors = [
+        (0.0, -1.0, -1.0, 0.0),
+        (-1.0, 1.0, 0.0, 1.0),
+        (1.0, 0.0, 1.0, -1.0),
+    ];
+    let m31_mirrors = [
+        (0.0, 1.0, 1.0, 0.0),
+        (1.0, -1.0, 0.0, -1.0),
+        (-1.0, 0.0, -1.0, 1.0),
+    ];
+
+    let at = |ops: &[(f32, f32, f32, f32)], t: (f32, f32)| {
+        ops.iter()
+            .map(|m| (*m, t))
+            .collect::<Vec<((f32, f32, f32, f32), (f32, f32))>>()
+    };
+
+    let origin = (0.0, 0.0);
+    let half = (0.5, 0.5);
+
+    let mut ops = at(&[identity], origin);
+
+    let centered = match group {
+        WallpaperGroup::P1 => false,
+        WallpaperGroup::P2 => {
+            ops.extend(at(&[half_turn], origin));
+            false
         }
-    }
+        WallpaperGroup::Pm => {
+            ops.extend(at(&mirrors[..1], origin));
+            false
+        }
+        WallpaperGroup::Pg => {
+            ops.extend(at(&mirrors[..1], (0.0, 0.5)));
+            false
+        }
+        WallpaperGroup::Cm => {
+            ops.extend(at(&mirrors[..1], origin));
+            true
+        }
+        WallpaperGroup::Pmm => {
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&mirrors, origin));
+            false
+        }
+        WallpaperGroup::Pmg => {
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&mirrors, (0.5, 0.0)));
+            false
+        }
+        WallpaperGroup::Pgg => {
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&mirrors, half));
+            false
+        }
+        WallpaperGroup::Cmm => {
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&mirrors, origin));
+            true
+        }
+        WallpaperGroup::P4 => {
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&quarter_turns, origin));
+            false
+        }
+        WallpaperGroup::P4m => {
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&quarter_turns, origin));
+            ops.extend(at(&mirrors, origin));
+            ops.extend(at(&diagonal_mirrors, origin));
+            false
+        }
+        WallpaperGroup::P4g => {
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&quarter_turns, origin));
+            ops.extend(at(&mirrors, half));
+            ops.extend(at(&diagonal_mirrors, half));
+            false
+        }
+        WallpaperGroup::P3 => {
+            ops.extend(at(&third_turns, origin));
+            true
+        }
+        WallpaperGroup::P3m1 => {
+            ops.extend(at(&third_turns, origin));
+            ops.extend(at(&m1_mirrors, origin));
+            true
+        }
+        WallpaperGroup::P31m => {
+            ops.extend(at(&third_turns, origin));
+            ops.extend(at(&m31_mirrors, origin));
+            true
+        }
+        WallpaperGroup::P6 => {
+            ops.extend(at(&third_turns, origin));
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&sixth_turns, origin));
+            true
+        }
+        WallpaperGroup::P6m => {
+            ops.extend(at(&third_turns, origin));
+            ops.extend(at(&[half_turn], origin));
+            ops.extend(at(&sixth_turns, origin));
+            ops.extend(at(&m1_mirrors, origin));
+            ops.extend(at(&m31_mirrors, origin));
+            true
+        }
+    };
 
-    doc
+    (ops, centered)
 }
 
-/// Un Deus Trois
+/// wallpaper
 ///
-/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/un_deus_trois.svg)
+/// https://en.wikipedia.org/wiki/Wallpaper_group
+///
+/// ![](https://raw.githubusercontent.com/suyash/geopattern-rs/master/examples/readme/wallpaper.svg)
+///
+/// `motif` is drawn in the coordinates of a rectangular cell of size `(cell_width, cell_height)`
+/// with the origin at its top left, and is replicated by every symmetry of `group`, so any motif
+/// placed near the origin produces a seamless tile.
+///
+/// The square groups `P4`, `P4m` and `P4g` require `cell_width == cell_height`. The hexagonal
+/// groups `P3`, `P3m1`, `P31m`, `P6` and `P6m` have lattice spacing `cell_width` and require
+/// `cell_height == cell_width * 3.0_f32.sqrt()`.
 ///
 /// ```
-/// use geopattern::un_deus_trois;
+/// use geopattern::{wallpaper, WallpaperGroup};
+/// use svg::node::element::Path;
 ///
-/// let c = un_deus_trois(
-///     60.0,
+/// let c = wallpaper(
+///     WallpaperGroup::P4g,
+///     Path::new()
+///         .set("d", "M 4 4 L 20 6 L 8 14 Z")
+///         .set("fill", "#222")
+///         .set("fill-opacity", 0.6),
+///     (40.0, 40.0),
 ///     (2, 2),
-///     &(0..12)
-///         .map(|v| {
-///             (
-///                 if v & 1 == 0 { "#222" } else { "#ddd" },
-///                 2.0,
-///                 0.02 + (v as f32) / 4.0,
-///             )
-///         })
-///         .collect::<Vec<(&str, f32, f32)>>(),
-///     &(0..12)
-///         .map(|v| 30.0 * v as f32)
-///         .collect::<Vec<f32>>(),
 ///     "#987987",
 /// );
 ///
 /// println!("{}", c);
 /// ```
-pub fn un_deus_trois(
-    step_size: f32,
+pub fn wallpaper<T>(
+    group: WallpaperGroup,
+    motif: T,
+    (cell_width, cell_height): (f32, f32),
     (width, height): (usize, usize),
-    stroke: &[(&str, f32, f32)],
-    rotation: &[f32],
     background_color: &str,
-) -> Document {
-    debug_assert_eq!(rotation.len(), 3 * width * height);
-    debug_assert_eq!(stroke.len(), 3 * width * height);
-
-    let mut doc = create_document(
-        (step_size * width as f32, step_size * height as f32 * 3.0),
-        background_color,
-    );
-
-    // Un
-    for y in 0..height {
-        for x in 0..width {
-            let (stroke, stroke_width, stroke_opacity) = stroke[y * width + x];
+) -> Document
+where
+    T: svg::Node + Clone,
+{
+    let hexagonal = match group {
+        WallpaperGroup::P4 | WallpaperGroup::P4m | WallpaperGroup::P4g => {
+            debug_assert!((cell_width - cell_height).abs() < 1e-3 * cell_width);
+            false
+        }
+        WallpaperGroup::P3
+        | WallpaperGroup::P3m1
+        | WallpaperGroup::P31m
+        | WallpaperGroup::P6
+        | WallpaperGroup::P6m => {
+            debug_assert!((cell_width * 3.0_f32.sqrt() - cell_height).abs() < 1e-3 * cell_width);
+            true
+        }
+        _ => false,
+    };
 
-            doc = doc.add(
-                Path::new()
-                    .set(
-                        "d",
-                        format!(
-                            "M {} {} L {} {}",
-                            (x as f32 + 0.5) * step_size,
-                            y as f32 * step_size,
-                            (x as f32 + 0.5) * step_size,
-                            (y + 1) as f32 * step_size
-                        ),
-                    )
-                    .set("stroke", stroke)
-                    .set("stroke-width", stroke_width)
-                    .set("stroke-opacity", stroke_opacity)
-                    .set(
-                        "transform",
-                        format!(
-                            "rotate({} {} {})",
-                            rotation[y * width + x],
-                            (x as f32 + 0.5) * step_size,
-                            (y as f32 + 0.5) * step_size
-                        ),
-                    ),
+    // lattice basis as columns, (a, b) with a 120 degree angle for the hexagonal groups
+    let (s00, s01, s10, s11) = if hexagonal {
+        (cell_width, -cell_width / 2.0, 0.0, cell_height / 2.0)
+    } else {
+        (cell_width, 0.0, 0.0, cell_height)
+    };
+    let det = s00 * s11 - s01 * s10;
+    let (i00, i01, i10, i11) = (s11 / det, -s01 / det, -s10 / det, s00 / det);
+
+    let (ops, centered) = wallpaper_operations(group);
+
+    // convert from lattice to cell coordinates, `(a, b, c, d, e, f)` as in svg `matrix`
+    let matrices = ops
+        .iter()
+        .map(|((m00, m01, m10, m11), (t0, t1))| {
+            let (p00, p01, p10, p11) = (
+                s00 * m00 + s01 * m10,
+                s00 * m01 + s01 * m11,
+                s10 * m00 + s11 * m10,
+                s10 * m01 + s11 * m11,
             );
-        }
-    }
 
-    // Deux
-    for y in 0..height {
-        for x in 0..width {
-            let (stroke, stroke_width, stroke_opacity) = stroke[(height + y) * width + x];
+            (
+                p00 * i00 + p01 * i10,
+                p10 * i00 + p11 * i10,
+                p00 * i01 + p01 * i11,
+                p10 * i01 + p11 * i11,
+                s00 * t0 + s01 * t1,
+                s10 * t0 + s11 * t1,
+            )
+        })
+        .collect::<Vec<(f32, f32, f32, f32, f32, f32)>>();
 
-            doc = doc.add(
-                Path::new()
-                    .set(
-                        "d",
-                        format!(
-                            "M {} {} L {} {} M {} {} L {} {}",
-                            (x as f32 + 0.2) * step_size,
-                            (height + y) as f32 * step_size,
-                            (x as f32 + 0.2) * step_size,
-                            (height + y + 1) as f32 * step_size,
-                            (x as f32 + 0.8) * step_size,
-                            (height + y) as f32 * step_size,
-                            (x as f32 + 0.8) * step_size,
-                            (height + y + 1) as f32 * step_size
-                        ),
-                    )
-                    .set("stroke", stroke)
-                    .set("stroke-width", stroke_width)
-                    .set("stroke-opacity", stroke_opacity)
-                    .set(
-                        "transform",
-                        format!(
-                            "rotate({} {} {})",
-                            rotation[(height + y) * width + x],
-                            (x as f32 + 0.5) * step_size,
-                            ((height + y) as f32 + 0.5) * step_size
-                        ),
-                    ),
-            );
-        }
-    }
+    let centers = if centered {
+        vec![(0.0, 0.0), (cell_width / 2.0, cell_height / 2.0)]
+    } else {
+        vec![(0.0, 0.0)]
+    };
 
-    // Trois
-    for y in 0..height {
-        for x in 0..width {
-            let (stroke, stroke_width, stroke_opacity) = stroke[(2 * height + y) * width + x];
+    let mut doc = create_document(
+        (cell_width * width as f32, cell_height * height as f32),
+        background_color,
+    );
 
-            doc = doc.add(
-                Path::new()
-                    .set(
-                        "d",
-                        format!(
-                            "M {} {} L {} {} M {} {} L {} {} M {} {} L {} {}",
-                            (x as f32 + 0.1) * step_size,
-                            (2 * height + y) as f32 * step_size,
-                            (x as f32 + 0.1) * step_size,
-                            (2 * height + y + 1) as f32 * step_size,
-                            (x as f32 + 0.5) * step_size,
-                            (2 * height + y) as f32 * step_size,
-                            (x as f32 + 0.5) * step_size,
-                            (2 * height + y + 1) as f32 * step_size,
-                            (x as f32 + 0.9) * step_size,
-                            (2 * height + y) as f32 * step_size,
-                            (x as f32 + 0.9) * step_size,
-                            (2 * height + y + 1) as f32 * step_size
-                        ),
-                    )
-                    .set("stroke", stroke)
-                    .set("stroke-width", stroke_width)
-                    .set("stroke-opacity", stroke_opacity)
-                    .set(
-                        "transform",
-                        format!(
-                            "rotate({} {} {})",
-                            rotation[(2 * height + y) * width + x],
-                            (x as f32 + 0.5) * step_size,
-                            ((2 * height + y) as f32 + 0.5) * step_size
-                        ),
-                    ),
-            );
+    // the images of a motif drawn in the cell can land up to two cells away from it
+    for y in -2..=height as isize + 1 {
+        for x in -2..=width as isize + 1 {
+            for (cx, cy) in centers.iter() {
+                for (a, b, c, d, e, f) in matrices.iter() {
+                    doc = doc.add(
+                        Group::new()
+                            .set(
+                                "transform",
+                                format!(
+                                    "matrix({} {} {} {} {} {})",
+                                    a,
+                                    b,
+                                    c,
+                                    d,
+                                    e + cx + x as f32 * cell_width,
+                                    f + cy + y as f32 * cell_height
+                                ),
+                            )
+                            .add(Clone::clone(&motif)),
+                    );
+                }
+            }
         }
     }
 
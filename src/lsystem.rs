@@ -0,0 +1,156 @@
+//! L-systems, interpreted by a turtle
+//!
+//! The turtle steps forward drawing a line on `F`, `G`, `A` and `B`, steps forward without
+//! drawing on `f`, turns by the angle on `+` and `-`, turns around on `|`, and saves and restores
+//! its position and heading on `[` and `]`. Every other symbol is only used by the rules.
+
+/// an L-system
+#[derive(Clone, Copy, Debug)]
+pub struct LSystem<'a> {
+    /// the initial string
+    pub axiom: &'a str,
+    /// rules `(symbol, replacement)` applied to every symbol in each iteration
+    pub rules: &'a [(char, &'a str)],
+    /// turning angle, in degrees
+    pub angle: f32,
+    /// initial heading of the turtle, in degrees clockwise from the positive x axis
+    pub heading: f32,
+}
+
+impl<'a> LSystem<'a> {
+    /// an L-system with the turtle initially heading along the positive x axis
+    pub fn new(axiom: &'a str, rules: &'a [(char, &'a str)], angle: f32) -> Self {
+        LSystem {
+            axiom,
+            rules,
+            angle,
+            heading: 0.0,
+        }
+    }
+
+    /// Heighway dragon
+    pub fn dragon_curve() -> LSystem<'static> {
+        LSystem::new("FX", &[('X', "X+YF+"), ('Y', "-FX-Y")], 90.0)
+    }
+
+    /// Koch snowflake
+    pub fn koch_snowflake() -> LSystem<'static> {
+        LSystem::new("F--F--F", &[('F', "F+F--F+F")], 60.0)
+    }
+
+    /// Sierpinski arrowhead curve
+    pub fn sierpinski_arrowhead() -> LSystem<'static> {
+        LSystem::new("A", &[('A', "B-A-B"), ('B', "A+B+A")], 60.0)
+    }
+
+    /// fractal plant, growing upwards
+    pub fn fractal_plant() -> LSystem<'static> {
+        LSystem {
+            heading: -90.0,
+            ..LSystem::new("X", &[('X', "F+[[X]-X]-F[-FX]+X"), ('F', "FF")], 25.0)
+        }
+    }
+
+    /// the string after applying the rules `iterations` times
+    pub fn expand(&self, iterations: usize) -> String {
+        let mut s = self.axiom.to_string();
+
+        for _ in 0..iterations {
+            s = s
+                .chars()
+                .map(|c| match self.rules.iter().find(|(k, _)| *k == c) {
+                    Some((_, v)) => v.to_string(),
+                    None => c.to_string(),
+                })
+                .collect();
+        }
+
+        s
+    }
+
+    /// the lines drawn by the turtle, with unit steps starting at the origin
+    pub fn lines(&self, iterations: usize) -> Vec<Vec<(f32, f32)>> {
+        let (mut x, mut y, mut heading) = (0.0, 0.0, self.heading);
+        let mut stack = Vec::new();
+
+        let mut lines = vec![vec![(x, y)]];
+
+        for c in self.expand(iterations).chars() {
+            match c {
+                'F' | 'G' | 'A' | 'B' | 'f' => {
+                    let (sin, cos) = heading.to_radians().sin_cos();
+                    x += cos;
+                    y += sin;
+
+                    if c == 'f' {
+                        lines.push(vec![(x, y)]);
+                    } else {
+                        lines.last_mut().unwrap().push((x, y));
+                    }
+                }
+                '+' => heading += self.angle,
+                '-' => heading -= self.angle,
+                '|' => heading += 180.0,
+                '[' => stack.push((x, y, heading)),
+                ']' => {
+                    if let Some(state) = stack.pop() {
+                        let (px, py, ph) = state;
+                        x = px;
+                        y = py;
+                        heading = ph;
+                        lines.push(vec![(x, y)]);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        lines.retain(|line| line.len() > 1);
+        lines
+    }
+
+    /// the lines drawn by the turtle, scaled uniformly and centered to fit `(width, height)`
+    /// with half a step of margin
+    pub fn fitted_lines(
+        &self,
+        iterations: usize,
+        (width, height): (f32, f32),
+    ) -> Vec<Vec<(f32, f32)>> {
+        let lines = self.lines(iterations);
+
+        let (min_x, max_x, min_y, max_y) = lines.iter().flatten().fold(
+            (f32::MAX, f32::MIN, f32::MAX, f32::MIN),
+            |(a, b, c, d), (x, y)| (a.min(*x), b.max(*x), c.min(*y), d.max(*y)),
+        );
+
+        let scale = (width / (max_x - min_x + 1.0)).min(height / (max_y - min_y + 1.0));
+        let (dx, dy) = (
+            (width - (max_x - min_x) * scale) / 2.0 - min_x * scale,
+            (height - (max_y - min_y) * scale) / 2.0 - min_y * scale,
+        );
+
+        lines
+            .into_iter()
+            .map(|line| {
+                line.into_iter()
+                    .map(|(x, y)| (x * scale + dx, y * scale + dy))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// svg path data for the lines drawn by the turtle, fit to `(width, height)`
+    pub fn path_data(&self, iterations: usize, (width, height): (f32, f32)) -> String {
+        let mut path = String::new();
+
+        for line in self.fitted_lines(iterations, (width, height)) {
+            path.push_str(&format!(" M {} {}", line[0].0, line[0].1));
+
+            for (x, y) in line.iter().skip(1) {
+                path.push_str(&format!(" L {} {}", x, y));
+            }
+        }
+
+        path.trim_start().to_string()
+    }
+}
@@ -0,0 +1,324 @@
+//! Deterministic noise, seeded, for the inputs of the organic patterns
+//!
+//! Every value is computed from a hash of the seed and the lattice point, so the same `Noise`
+//! gives the same values on every platform and in any order of sampling.
+
+use crate::Rng;
+
+/// enum to identify the kind of noise
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoiseKind {
+    /// random values on a square lattice, smoothly interpolated
+    Value,
+    /// random gradients on a square lattice, zero on the lattice points
+    Perlin,
+    /// random gradients on a triangular lattice, with fewer directional artifacts than `Perlin`
+    Simplex,
+}
+
+/// seeded noise, summed over octaves as fractal Brownian motion
+#[derive(Clone, Copy, Debug)]
+pub struct Noise {
+    /// the kind of noise in each octave
+    pub kind: NoiseKind,
+    /// the seed, every octave uses a different seed derived from it
+    pub seed: u64,
+    /// lattice cells per unit of input, in the first octave
+    pub frequency: f32,
+    /// number of octaves, each with twice the frequency of the one before it
+    pub octaves: usize,
+    /// amplitude of each octave relative to the one before it
+    pub gain: f32,
+    /// when set, the noise repeats with this period in the input coordinates, and the frequency
+    /// is rounded so a whole number of lattice cells fits in the period
+    pub period: Option<(f32, f32)>,
+}
+
+impl Noise {
+    /// a single octave of noise that does not repeat
+    pub fn new(kind: NoiseKind, seed: u64, frequency: f32) -> Self {
+        Noise {
+            kind,
+            seed,
+            frequency,
+            octaves: 1,
+            gain: 0.5,
+            period: None,
+        }
+    }
+
+    /// the noise at `(x, y)`, in roughly `[-1, 1]`
+    pub fn get(&self, x: f32, y: f32) -> f32 {
+        let (mut sum, mut total, mut amplitude) = (0.0, 0.0, 1.0);
+
+        for octave in 0..self.octaves.max(1) {
+            let scale = (1u64 << octave) as f32;
+            let seed = self.seed.wrapping_add(octave as u64);
+
+            let value = match self.period {
+                Some((width, height)) => {
+                    let (cells_x, cells_y) = (
+                        (width * self.frequency).round().max(1.0) * scale,
+                        (height * self.frequency).round().max(1.0) * scale,
+                    );
+
+                    basis(
+                        self.kind,
+                        seed,
+                        (x / width * cells_x, y / height * cells_y),
+                        Some((cells_x as i64, cells_y as i64)),
+                    )
+                }
+                None => basis(
+                    self.kind,
+                    seed,
+                    (x * self.frequency * scale, y * self.frequency * scale),
+                    None,
+                ),
+            };
+
+            sum += value * amplitude;
+            total += amplitude;
+            amplitude *= self.gain;
+        }
+
+        sum / total
+    }
+}
+
+fn hash(seed: u64, i: i64, j: i64) -> u64 {
+    Rng::new(
+        seed ^ (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (j as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F),
+    )
+    .next_u64()
+}
+
+/// one of 8 unit gradients, dotted with `(x, y)`
+fn gradient(hash: u64, x: f32, y: f32) -> f32 {
+    let angle = (hash & 7) as f32 * std::f32::consts::FRAC_PI_4;
+    angle.cos() * x + angle.sin() * y
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f32, a: f32, b: f32) -> f32 {
+    a + t * (b - a)
+}
+
+/// a single octave, with the lattice wrapped around `period` cells when it is set
+fn basis(kind: NoiseKind, seed: u64, (x, y): (f32, f32), period: Option<(i64, i64)>) -> f32 {
+    let wrap = |i: i64, j: i64| match period {
+        Some((px, py)) => (i.rem_euclid(px), j.rem_euclid(py)),
+        None => (i, j),
+    };
+
+    match kind {
+        NoiseKind::Value | NoiseKind::Perlin => {
+            let (x0, y0) = (x.floor(), y.floor());
+            let (i, j) = (x0 as i64, y0 as i64);
+            let (x, y) = (x - x0, y - y0);
+
+            let corner = |di: i64, dj: i64| {
+                let (ci, cj) = wrap(i + di, j + dj);
+                let h = hash(seed, ci, cj);
+
+                match kind {
+                    NoiseKind::Value => (h >> 11) as f32 / (1u64 << 53) as f32 * 2.0 - 1.0,
+                    _ => gradient(h, x - di as f32, y - dj as f32) * std::f32::consts::SQRT_2,
+                }
+            };
+
+            let (u, v) = (fade(x), fade(y));
+
+            lerp(
+                v,
+                lerp(u, corner(0, 0), corner(1, 0)),
+                lerp(u, corner(0, 1), corner(1, 1)),
+            )
+        }
+        NoiseKind::Simplex => match period {
+            // the triangular lattice does not line up with a rectangular period, so the noise is
+            // blended with its copies one period away instead
+            Some((px, py)) => {
+                let (px, py) = (px as f32, py as f32);
+                let (x, y) = (x.rem_euclid(px), y.rem_euclid(py));
+                let (u, v) = (fade(x / px), fade(y / py));
+
+                lerp(
+                    v,
+                    lerp(u, simplex(seed, x, y), simplex(seed, x - px, y)),
+                    lerp(u, simplex(seed, x, y - py), simplex(seed, x - px, y - py)),
+                )
+            }
+            None => simplex(seed, x, y),
+        },
+    }
+}
+
+fn simplex(seed: u64, x: f32, y: f32) -> f32 {
+    let f2 = (3.0_f32.sqrt() - 1.0) / 2.0;
+    let g2 = (3.0 - 3.0_f32.sqrt()) / 6.0;
+
+    let s = (x + y) * f2;
+    let (i, j) = ((x + s).floor(), (y + s).floor());
+    let t = (i + j) * g2;
+    let (x0, y0) = (x - (i - t), y - (j - t));
+
+    let (i1, j1) = if x0 > y0 { (1.0, 0.0) } else { (0.0, 1.0) };
+
+    let corners = [
+        (0.0, 0.0, x0, y0),
+        (i1, j1, x0 - i1 + g2, y0 - j1 + g2),
+        (1.0, 1.0, x0 - 1.0 + 2.0 * g2, y0 - 1.0 + 2.0 * g2),
+    ];
+
+    70.0 * corners
+        .iter()
+        .map(|(di, dj, x, y)| {
+            let t = 0.5 - x * x - y * y;
+
+            if t > 0.0 {
+                t.powi(4) * gradient(hash(seed, (i + di) as i64, (j + dj) as i64), *x, *y)
+            } else {
+                0.0
+            }
+        })
+        .sum::<f32>()
+}
+
+/// `pulse_heights` for `joy_division`, up to `amplitude` above each line
+///
+/// The pulses fade out towards the left and right edges, so the lines tile horizontally. `noise`
+/// is sampled at the centre of each cell, since `Perlin` noise is zero on the lattice points.
+///
+/// ```
+/// use geopattern::joy_division;
+/// use geopattern::noise::{joy_division_pulse_heights, Noise, NoiseKind};
+///
+/// let noise = Noise {
+///     octaves: 3,
+///     ..Noise::new(NoiseKind::Perlin, 7, 0.3)
+/// };
+///
+/// let c = joy_division(
+///     10.0,
+///     (20, 8),
+///     &joy_division_pulse_heights(&noise, (20, 8), 30.0),
+///     ("#333", 0.5, 2.0),
+///     2,
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn joy_division_pulse_heights(
+    noise: &Noise,
+    (width, height): (usize, usize),
+    amplitude: f32,
+) -> Vec<f32> {
+    (0..height)
+        .flat_map(|y| {
+            (0..width).map(move |x| {
+                let envelope = (std::f32::consts::PI * (x + 1) as f32 / width as f32)
+                    .sin()
+                    .powi(2);
+
+                -amplitude * envelope * (noise.get(x as f32 + 0.5, y as f32 + 0.5) + 1.0) / 2.0
+            })
+        })
+        .collect()
+}
+
+/// `(translate, rotate)` for `cubic_disarray`, up to `max_translate` and `max_rotate` degrees in
+/// either direction
+///
+/// Both sample `noise` at the cell centres, so they tile when `noise.period` is the size of the
+/// pattern in cells, and are not zero everywhere for `Perlin` noise at a whole frequency.
+///
+/// ```
+/// use geopattern::cubic_disarray;
+/// use geopattern::noise::{cubic_disarray_offsets, Noise, NoiseKind};
+///
+/// let noise = Noise {
+///     period: Some((4.0, 4.0)),
+///     ..Noise::new(NoiseKind::Simplex, 7, 0.5)
+/// };
+///
+/// let (translate, rotate) = cubic_disarray_offsets(&noise, (4, 4), (6.0, 20.0));
+///
+/// let c = cubic_disarray(
+///     30.0,
+///     (4, 4),
+///     &(0..16).map(|_| ("#ddd", 0.8)).collect::<Vec<(&str, f32)>>(),
+///     ("#222", 1.0),
+///     (&translate, &rotate),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn cubic_disarray_offsets(
+    noise: &Noise,
+    (width, height): (usize, usize),
+    (max_translate, max_rotate): (f32, f32),
+) -> (Vec<f32>, Vec<f32>) {
+    let rotation = Noise {
+        seed: noise.seed.wrapping_add(0x5EED),
+        ..*noise
+    };
+
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x as f32 + 0.5, y as f32 + 0.5)))
+        .map(|(x, y)| {
+            (
+                noise.get(x, y) * max_translate,
+                rotation.get(x, y) * max_rotate,
+            )
+        })
+        .unzip()
+}
+
+/// `entropy` for `triangular_mesh`, moving each point up to `amplitude` along each axis
+///
+/// The points sample `noise` half a cell away from the vertex coordinates, off the lattice points
+/// where `Perlin` noise is zero, so the last row still matches the first when `noise.period` is
+/// `(width, height)`, and the mesh tiles.
+///
+/// ```
+/// use geopattern::noise::{triangular_mesh_entropy, Noise, NoiseKind};
+/// use geopattern::triangular_mesh;
+///
+/// let noise = Noise {
+///     period: Some((2.0, 2.0)),
+///     ..Noise::new(NoiseKind::Value, 7, 1.0)
+/// };
+///
+/// let c = triangular_mesh(
+///     60.0,
+///     (2, 2),
+///     &triangular_mesh_entropy(&noise, (2, 2), 10.0),
+///     &(0..12).map(|_| ("#ddd", 0.2)).collect::<Vec<(&str, f32)>>(),
+///     (1.0, "#ddd", 0.2),
+///     "#987987",
+/// );
+///
+/// println!("{}", c);
+/// ```
+pub fn triangular_mesh_entropy(
+    noise: &Noise,
+    (width, height): (usize, usize),
+    amplitude: f32,
+) -> Vec<(f32, f32)> {
+    let vertical = Noise {
+        seed: noise.seed.wrapping_add(0x5EED),
+        ..*noise
+    };
+
+    (0..=height)
+        .flat_map(|y| (0..width).map(move |x| (x as f32 + 0.5, y as f32 + 0.5)))
+        .map(|(x, y)| (noise.get(x, y) * amplitude, vertical.get(x, y) * amplitude))
+        .collect()
+}